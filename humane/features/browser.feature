@@ -0,0 +1,165 @@
+Feature: Browser Tests
+
+    Background:
+        Given I have a "public/index.html" file with the content:
+            """
+            <html lang="en">
+            <head><title>Home</title></head>
+            <body>
+                <h1>Hello</h1>
+                <p class="intro">  Welcome to the site  </p>
+                <a href="/about/" data-title='Say "hi"'>About</a>
+                <input id="search" aria-label="Search">
+                <div id="secret" style="display: none">Secret</div>
+                <ul><li>One</li><li>Two</li><li>Three</li></ul>
+                <div id="loading">Loading</div>
+                <script>
+                    setTimeout(() => {
+                        document.getElementById('loading').remove();
+                        const result = document.createElement('p');
+                        result.id = 'result';
+                        result.innerText = `It's "loaded"`;
+                        document.body.appendChild(result);
+                        window.loaded = true;
+                    }, 300);
+                </script>
+            </body>
+            </html>
+            """
+
+    Scenario: Elements can be checked in the browser
+        When I serve the "public" directory
+        When I load "/"
+        Then The selector "h1" should exist
+        Then The selector "h1" should contain "Hello"
+        Then The selector ".intro" should contain "  Welcome to the site  "
+        Then The selector ".intro" should contain the text "Welcome"
+        Then The selector ".intro" should have the text "Welcome to the site"
+        Then The selector ".intro" should have the html "Welcome to the site"
+        Then The selector ".intro" should match the text /^Welcome to \w+ site$/
+        Then The selector "[data-title='Say "hi"']" should have the attribute "href" with the value "/about/"
+        Then The selector "a" should have the attribute "data-title" with the value "Say "hi""
+        Then The selector "a" should have the attribute "href"
+        Then The selector "a" should not have the attribute "target"
+        Then The selector "h1" should have the style "display" with the value "block"
+        Then The selector "h1" should be visible
+        Then The selector "#secret" should be hidden
+        Then The selector "#missing" should be hidden or not exist
+        Then There should be 3 elements matching "li"
+        Then There should be 1 element matching "h1"
+        When I click "#search"
+        Then The selector "#search" should be focused
+        Then The selector "a" should not be focused
+        Then There should be no logs
+
+    Scenario: Browser steps wait for the page
+        When I serve the "public" directory
+        When I load "/"
+        When I wait for "#loading" to be visible
+        When I wait for "#secret" to be hidden
+        When I wait for "#result" to be visible within 1000ms
+        When I wait for "#loading" to be detached
+        When I wait for "#loading" to be hidden or not exist
+        When I wait for the text "It's "loaded"" to appear
+        When I wait for the script to return a truthy value:
+            """
+            () => window.loaded
+            """
+        When I wait for the network to be idle
+        Then The selector "#result" should have the text "It's "loaded"" within 500ms
+        When I evaluate:
+            """
+            () => console.log("Done")
+            """
+        When I wait for the script to return a truthy value within 100ms:
+            """
+            async () => console.events.includes("LOG: Done")
+            """
+
+    Scenario: Failed browser steps describe what the page had
+        Given I have a "failing/assertions.feature" file with the content:
+            """
+            Feature: Failing Assertions
+
+                Background:
+                    Given I have a "public/index.html" file with the content:
+                        ```
+                        <h1>Hello</h1>
+                        <div id="secret" style="display: none">Secret</div>
+                        ```
+                    When I serve the "public" directory
+                    When I load "/"
+
+                Scenario: Text
+                    Then The selector "h1" should contain the text "Goodbye" within 100ms
+
+                Scenario: Missing element visibility
+                    Then The selector "h2" should be hidden within 100ms
+
+                Scenario: Missing element
+                    Then The selector "h2" should exist within 100ms
+
+                Scenario: Count
+                    Then There should be 2 elements matching "h1" within 100ms
+
+                Scenario: Hidden click
+                    When I click "#secret" within 100ms
+
+                Scenario: Script
+                    When I wait for the script to return a truthy value within 100ms:
+                        ```
+                        () => window.missing
+                        ```
+
+                Scenario: Logs
+                    When I evaluate:
+                        ```
+                        () => console.error("Oops")
+                        ```
+                    Then There should be no logs
+            """
+        When I run my program with the flags:
+            | --test-file-root failing |
+        Then I should see "7 scenarios (7 failed)" in stdout
+        Then I should see "Expected the text of h1 to contain "Goodbye", but after 100ms it was:" in stdout
+        Then I should see "Expected the visibility of h2 to be hidden, but after 100ms it was:" in stdout
+        Then I should see "Timed out after 100ms waiting for h2 to exist" in stdout
+        Then I should see "Expected the number of elements matching h1 to be 2, but after 100ms it was:" in stdout
+        Then I should see "Timed out after 100ms waiting for #secret to be visible" in stdout
+        Then I should see "Timed out after 100ms waiting for the script to return a truthy value" in stdout
+        Then I should see "ERR: Oops" in stdout
+
+    Scenario: The browser is launched with the configured options
+        Given I have a "humane.yml" file with the content:
+            """
+            browser:
+              window_width: 640
+              window_height: 480
+              wait_timeout: 150
+            """
+        Given I have a "configured/browser.feature" file with the content:
+            """
+            Feature: Configured Browser
+
+                Background:
+                    Given I have a "public/index.html" file with the content:
+                        ```
+                        <h1>Hello</h1>
+                        ```
+                    When I serve the "public" directory
+                    When I load "/"
+
+                Scenario: Window size
+                    When I wait for the script to return a truthy value within 100ms:
+                        ```
+                        () => window.innerWidth === 640 && window.innerHeight === 480
+                        ```
+
+                Scenario: Wait timeout
+                    Then The selector "h2" should exist
+            """
+        When I run my program with the flags:
+            | --test-file-root configured |
+            | --slow-mo 10                |
+        Then I should see "2 scenarios (1 passed, 1 failed)" in stdout
+        Then I should see "Timed out after 150ms waiting for h2 to exist" in stdout
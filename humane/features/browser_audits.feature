@@ -0,0 +1,147 @@
+Feature: Browser Audit Tests
+
+    Scenario: Pages can be audited for accessibility
+        Given I have a "public/index.html" file with the content:
+            """
+            <html lang="en">
+            <head><title>Search</title></head>
+            <body>
+                <main>
+                    <h1>Search</h1>
+                    <label for="query">Query</label>
+                    <input id="query">
+                    <button>Go</button>
+                    <img src="/logo.svg" alt="Logo">
+                </main>
+                <aside>
+                    <img src="/logo.svg">
+                    <button></button>
+                </aside>
+            </body>
+            </html>
+            """
+        Given I have a "public/logo.svg" file with the content:
+            """
+            <svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"><rect width="10" height="10"/></svg>
+            """
+        When I serve the "public" directory
+        When I load "/"
+        Then the page should have no accessibility violations in "main"
+        Then the page should have no accessibility violations for the rules "document-title, label"
+        Then the page should have no accessibility violations in "main" for the rule "image-alt"
+
+    Scenario: Failed accessibility audits list each violation
+        Given I have a "failing/accessibility.feature" file with the content:
+            """
+            Feature: Failing Accessibility
+
+                Background:
+                    Given I have a "public/index.html" file with the content:
+                        ```
+                        <html lang="en">
+                        <head><title>Search</title></head>
+                        <body>
+                            <img src="data:image/svg+xml,<svg xmlns='http://www.w3.org/2000/svg' width='10' height='10'/>">
+                            <button></button>
+                        </body>
+                        </html>
+                        ```
+                    When I serve the "public" directory
+                    When I load "/"
+
+                Scenario: Violations
+                    Then the page should have no accessibility violations
+
+                Scenario: Unknown rule
+                    Then the page should have no accessibility violations for the rule "colour"
+            """
+        When I run my program with the flags:
+            | --test-file-root failing |
+        Then I should see "2 scenarios (2 failed)" in stdout
+        Then I should see "Expected no accessibility violations, but found" in stdout
+        Then I should see "[image-alt] Image has no alternative text" in stdout
+        Then I should see "[button-name] Button has no accessible name" in stdout
+        Then I should see "Unknown accessibility rule "colour". Available rules:" in stdout
+
+    Scenario: JavaScript coverage is written at the end of the run
+        Given I have a "covered/coverage.feature" file with the content:
+            """
+            Feature: Coverage
+
+                Scenario: Scripts are covered
+                    Given I have a "public/index.html" file with the content:
+                        ```
+                        <script src="/app.js"></script>
+                        ```
+                    Given I have a "public/app.js" file with the content:
+                        ```
+                        function used() { return 1; }
+                        function unused() { return 2; }
+                        used();
+                        ```
+                    Given I have a "docs/index.html" file with the content:
+                        ```
+                        <script src="/app.js"></script>
+                        ```
+                    Given I have a "docs/app.js" file with the content:
+                        ```
+                        function documented() { return 3; }
+                        ```
+                    When I serve the "public" directory
+                    When I serve the "docs" directory as "docs"
+                    When I load "/"
+                    When I load "/" from "docs"
+            """
+        When I run my program with the flags:
+            | --test-file-root covered  |
+            | --coverage coverage.info  |
+        Then I should see "1 scenario (1 passed)" in stdout
+        Then I should see "SF:app.js" in "coverage.info"
+        Then I should see "SF:docs/app.js" in "coverage.info"
+        Then I should see "unused" in "coverage.info"
+        Then I should not see "localhost" in "coverage.info"
+        When I run my program with the flags:
+            | --test-file-root covered  |
+            | --coverage coverage.json  |
+        Then I should see ""app.js"" in "coverage.json"
+        Then I should see ""docs/app.js"" in "coverage.json"
+
+    Scenario: Scenarios sharing a browser process stay isolated
+        Given I have a "humane.yml" file with the content:
+            """
+            browser:
+              pool_size: 2
+            """
+        Given I have a "pooled/pool.feature" file with the content:
+            """
+            Feature: Pool
+
+                Background:
+                    Given I have a "public/index.html" file with the content:
+                        ```
+                        <h1>Home</h1>
+                        ```
+                    When I serve the "public" directory
+
+                Scenario: First
+                    Given the cookie "shared" is "first"
+                    When I load "/"
+                    When I evaluate:
+                        ```
+                        () => localStorage.setItem('shared', 'first')
+                        ```
+                    Then the cookie "shared" should be "first"
+
+                Scenario: Second
+                    When I load "/"
+                    Then the cookie "shared" should not exist
+                    Then the local storage item "shared" should not exist
+
+                Scenario: Third
+                    When I load "/"
+                    Then the cookie "shared" should not exist
+                    Then the local storage item "shared" should not exist
+            """
+        When I run my program with the flags:
+            | --test-file-root pooled |
+        Then I should see "3 scenarios (3 passed)" in stdout
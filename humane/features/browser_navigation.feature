@@ -0,0 +1,142 @@
+Feature: Browser Navigation Tests
+
+    Background:
+        Given I have a "public/index.html" file with the content:
+            """
+            <h1>Home</h1>
+            <a href="/about/">About</a>
+            """
+        Given I have a "public/about/index.html" file with the content:
+            """
+            <h1>About</h1>
+            """
+
+    Scenario: Pages can be navigated through
+        When I serve the "public" directory
+        When I load "/"
+        When I click "a" and wait for navigation
+        Then The selector "h1" should have the text "About"
+        When I go back
+        Then The selector "h1" should have the text "Home"
+        When I go forward within 1000ms
+        Then The selector "h1" should have the text "About"
+        When I evaluate:
+            """
+            () => { document.querySelector('h1').innerText = 'Changed'; }
+            """
+        Then The selector "h1" should have the text "Changed"
+        When I reload the page
+        Then The selector "h1" should have the text "About"
+
+    Scenario: Pages can be opened in tabs
+        Given I have a "docs/index.html" file with the content:
+            """
+            <h1>Docs</h1>
+            """
+        When I serve the "public" directory
+        When I serve the "docs" directory as "docs"
+        When I load "/"
+        When I open "/" from "docs" in a new tab "docs"
+        Then The selector "h1" should have the text "Docs"
+        When I open "/about/" in a new tab "about"
+        Then The selector "h1" should have the text "About"
+        When I switch to the tab "main"
+        Then The selector "h1" should have the text "Home"
+        When I switch to the tab "docs"
+        Then The selector "h1" should have the text "Docs"
+
+    Scenario: Failed navigation steps describe what went wrong
+        Given I have a "failing/navigation.feature" file with the content:
+            """
+            Feature: Failing Navigation
+
+                Background:
+                    Given I have a "public/index.html" file with the content:
+                        ```
+                        <h1>Home</h1>
+                        <a href="#top">Top</a>
+                        ```
+                    When I serve the "public" directory
+                    When I load "/"
+
+                Scenario: Missing tab
+                    When I switch to the tab "second"
+
+                Scenario: Duplicate tab
+                    When I open "/" in a new tab "main"
+
+                Scenario: End of history
+                    When I go forward
+
+                Scenario: No navigation
+                    When I click "a" and wait for navigation within 100ms
+            """
+        When I run my program with the flags:
+            | --test-file-root failing |
+        Then I should see "4 scenarios (4 failed)" in stdout
+        Then I should see "No tab named "second" is open. Open tabs: ["main"]" in stdout
+        Then I should see "A tab named "main" is already open" in stdout
+        Then I should see "There is no page ahead in the history" in stdout
+        Then I should see "Timed out after 100ms waiting for the page to navigate" in stdout
+
+    Scenario: The browser can emulate devices and preferences
+        Given I have a "public/index.html" file with the content:
+            """
+            <meta name="viewport" content="width=device-width">
+            <style>
+                h1 { color: rgb(0, 0, 0); animation: fade 1s; }
+                @keyframes fade { from { opacity: 0; } }
+                @media (prefers-color-scheme: dark) { h1 { color: rgb(255, 255, 255); } }
+                @media (prefers-reduced-motion: reduce) { h1 { animation: none; } }
+                @media (max-width: 400px) { h1 { text-transform: uppercase; } }
+            </style>
+            <h1>Home</h1>
+            """
+        When I serve the "public" directory
+        When I load "/"
+        Then The selector "h1" should have the style "color" with the value "rgb(0, 0, 0)"
+        Then The selector "h1" should have the style "animation-name" with the value "fade"
+        Then The selector "h1" should have the style "text-transform" with the value "none"
+        Given the browser prefers dark mode
+        Given the browser prefers reduced motion
+        Then The selector "h1" should have the style "color" with the value "rgb(255, 255, 255)"
+        Then The selector "h1" should have the style "animation-name" with the value "none"
+        Given the browser prefers light mode
+        Then The selector "h1" should have the style "color" with the value "rgb(0, 0, 0)"
+        Given I emulate a mobile device
+        Then The selector "h1" should have the style "text-transform" with the value "uppercase"
+        When I wait for the script to return a truthy value:
+            """
+            () => window.innerWidth === 375 && navigator.maxTouchPoints > 0 && navigator.userAgent.includes("Mobile")
+            """
+        Given the viewport is 1024x768
+        Then The selector "h1" should have the style "text-transform" with the value "none"
+
+    Scenario: Cookies and storage can be set and checked
+        Given I have a "public/index.html" file with the content:
+            """
+            <p id="cookie"></p>
+            <p id="storage"></p>
+            <script>
+                document.getElementById('cookie').innerText = document.cookie;
+                document.getElementById('storage').innerText = localStorage.getItem('visited');
+                sessionStorage.setItem('tab', 'open');
+                document.cookie = 'seen=yes';
+            </script>
+            """
+        When I serve the "public" directory
+        Given the cookie "theme" is "dark"
+        Given the local storage item "visited" is "before"
+        When I load "/"
+        Then The selector "#cookie" should have the text "theme=dark"
+        Then The selector "#storage" should have the text "before"
+        Then the cookie "seen" should be "yes"
+        Then the session storage item "tab" should be "open"
+        Given the local storage item "visited" is "after"
+        Then the local storage item "visited" should be "after"
+        When I clear the cookies
+        Then the cookie "theme" should not exist
+        When I clear the local storage
+        Then the local storage item "visited" should not exist
+        When I clear the session storage
+        Then the session storage item "tab" should not exist within 100ms
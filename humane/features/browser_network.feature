@@ -0,0 +1,116 @@
+Feature: Browser Network Tests
+
+    Scenario: Browser requests can be blocked, stubbed and checked
+        Given I have a "public/index.html" file with the content:
+            """
+            <p id="data"></p>
+            <p id="missing"></p>
+            <p id="open"></p>
+            <p id="closed"></p>
+            <script src="/tracker.js"></script>
+            <script>
+                const show = (id) => (text) => { document.getElementById(id).innerText = text; };
+                fetch('/data.json').then(r => r.json()).then(d => show('data')(d.title));
+                fetch('/missing.json').then(r => show('missing')(r.status));
+                fetch('https://api.example.com/open').then(r => r.text()).then(show('open'), () => show('open')('failed'));
+                fetch('https://api.example.com/closed').then(r => r.text()).then(show('closed'), () => show('closed')('failed'));
+            </script>
+            """
+        Given I have a "public/tracker.js" file with the content:
+            """
+            console.log("Tracked");
+            """
+        Given I have a "fixtures/data.json" file with the content:
+            """
+            {"title": "Stubbed"}
+            """
+        Given I have a "fixtures/open.txt" file with the content:
+            """
+            Open
+            """
+        When I serve the "public" directory
+        Given the browser blocks requests to "/tracker.js"
+        Given the browser responds to "/data.json" with the file "fixtures/data.json"
+        Given the browser responds to "/missing.json" with the status 404 with:
+            """
+            {"error": "Missing"}
+            """
+        Given the browser responds to "https://api.example.com/open" with the file "fixtures/open.txt"
+            | Access-Control-Allow-Origin | * |
+        Given the browser responds to "https://api.example.com/closed" with the file "fixtures/open.txt"
+        When I load "/"
+        Then The selector "#data" should have the text "Stubbed"
+        Then The selector "#missing" should have the text "404"
+        Then The selector "#open" should have the text "Open"
+        Then The selector "#closed" should have the text "failed"
+        Then There should be no logs
+        Then the page should have requested "/" 1 time with the method "GET" with the status 200
+        Then the page should have requested "/data.json" with the status 200
+        Then the page should have requested "/missing.json" with the status 404
+        Then the page should have requested "https://api.example.com/*" 2 times
+        Then the page should not have requested "/tracker.js" with the status 200
+        Then the page should not have requested "/pagefind/*"
+        Then the server should have received a request for "/" 1 time
+        Then the server should not have received a request for "/tracker.js"
+        Then the server should not have received a request for "/data.json"
+
+    Scenario: Page performance can be measured
+        Given I have a "public/index.html" file with the content:
+            """
+            <h1>Fast</h1>
+            <script src="/app.js"></script>
+            """
+        Given I have a "public/app.js" file with the content:
+            """
+            document.querySelector('h1').dataset.ready = 'true';
+            """
+        When I serve the "public" directory
+        When I load "/"
+        Then the page should load in under 5000ms
+        Then the page should transfer under 1MB
+        Then the page should transfer under 100 kB within 1000ms
+        Then the page should make at most 2 requests
+        Then the page metric "Nodes" should be over 1
+        Then the page metric "JSHeapUsedSize" should be under 100000000
+
+    Scenario: Failed network steps list what the page requested
+        Given I have a "failing/network.feature" file with the content:
+            """
+            Feature: Failing Network
+
+                Background:
+                    Given I have a "public/index.html" file with the content:
+                        ```
+                        <h1>Home</h1>
+                        ```
+                    When I serve the "public" directory
+                    When I load "/"
+
+                Scenario: Missing request
+                    Then the page should have requested "/other.html" within 100ms
+
+                Scenario: Unwanted request
+                    Then the page should not have requested "/"
+
+                Scenario: Request count
+                    Then the page should make at most 0 requests
+
+                Scenario: Transfer size
+                    Then the page should transfer under 1 B
+
+                Scenario: Metric
+                    Then the page metric "Nodes" should be under 1
+
+                Scenario: Missing metric
+                    Then the page metric "Missing" should be under 1
+            """
+        When I run my program with the flags:
+            | --test-file-root failing |
+        Then I should see "6 scenarios (6 failed)" in stdout
+        Then I should see "Expected the page to request" in stdout
+        Then I should see "but after 100ms it had requested it 0 time(s). All requests:" in stdout
+        Then I should see "Expected the page not to request" in stdout
+        Then I should see "Expected the page to make at most 0 request(s), but it made 1" in stdout
+        Then I should see "Expected the page to transfer under 1B, but it transferred" in stdout
+        Then I should see "Expected the page metric Nodes to be under 1, but it was" in stdout
+        Then I should see "The page has no metric "Missing". Available metrics:" in stdout
@@ -0,0 +1,15 @@
+Feature: Composite Steps
+
+    Scenario: Composite steps run the steps they define
+        Given I have a "peach" page saying "Peach!"
+        Then I should see "<h1>Peach!</h1>" in "public/peach.html"
+
+    Scenario: Composite steps can use other composite steps
+        Given I have a greeting site
+        Then I should see "<h1>Hello</h1>" in "public/index.html"
+        Then I should see "<h1>About us</h1>" in "public/about.html"
+
+    Scenario: Composite steps fill their parameters into tables
+        Given I have the greeting "Howdy"
+        When I run "echo {{env:GREETING}}"
+        Then I should see "Howdy" in stdout
@@ -0,0 +1,103 @@
+Feature: HTTP Tests
+
+    Scenario: Served directories can be requested
+        Given I have a "public/index.html" file with the content:
+            """
+            <h1>Hello</h1>
+            """
+        When I serve the "public" directory with:
+            | header:Cache-Control | max-age=60 |
+        When I request GET "/"
+        Then the response status should be 200
+        Then the response header "Content-Type" should contain "text/html"
+        Then the response header "Cache-Control" should be "max-age=60"
+        Then the response body should contain "<h1>Hello</h1>"
+        When I request GET "/missing.html"
+        Then the response status should be 404
+        Then the server should have received a request for "/" 1 time with the status 200
+        Then the server should have received a request for "/missing.html" with the status 404
+        Then the server should not have received a request for "/pagefind/*"
+
+    Scenario: Hidden files are not served
+        Given I have a "public/.env" file with the content:
+            """
+            SECRET=peach
+            """
+        Given I have a "public/.git/config" file with the content:
+            """
+            [core]
+            """
+        When I serve the "public" directory
+        When I request GET "/.env"
+        Then the response status should be 404
+        Then the response body should not contain "peach"
+        When I request GET "/.git/config"
+        Then the response status should be 404
+
+    Scenario: Not found pages are always served in full
+        Given I have a "public/404.html" file with the content:
+            """
+            <h1>Missing page</h1>
+            """
+        When I serve the "public" directory with:
+            | not found | 404.html |
+        When I request GET "/missing.html" with the headers:
+            | Range | bytes=0-3 |
+        Then the response status should be 404
+        Then the response body should contain "<h1>Missing page</h1>"
+
+    Scenario: Directories can be served over HTTPS
+        Given I have a "public/index.html" file with the content:
+            """
+            <h1>Secure</h1>
+            """
+        When I serve the "public" directory with:
+            | https | true |
+        When I request GET "/"
+        Then the response status should be 200
+        Then the response body should contain "<h1>Secure</h1>"
+        When I request GET "https://localhost:{{humane_port}}/"
+        Then the response status should be 200
+
+    Scenario: Named servers keep their own request logs
+        Given I have a "docs/index.html" file with the content:
+            """
+            <h1>Docs</h1>
+            """
+        When I serve the "docs" directory as "docs"
+        When I request HEAD "http://localhost:{{humane_port:docs}}/?search=peach"
+        Then the server "docs" should have received a request for "/?search=peach" with the method HEAD
+        Then the server "docs" should have received a request for "/"
+        Then the server should not have received a request for "/"
+
+    Scenario: The mock API answers requests
+        Given the mock API has the routes:
+            | GET | /api/sites | 200 | {"sites": [{"name": "peach", "pages": 3}]} |
+        Given the mock API responds to POST "/api/sites" with the status 201 with:
+            """
+            {"created": true}
+            """
+        When I request GET "{{humane_mock_url}}/api/sites"
+        Then the response status should be 200
+        Then the response header "Content-Type" should be "application/json"
+        Then the response should contain the JSON values:
+            | sites.0.name  | peach |
+            | sites.0.pages | int:3 |
+        Given the request header "Authorization" is "Bearer peach"
+        When I request POST "{{humane_mock_url}}/api/sites" with the body:
+            """
+            {"site": {"name": "pear"}}
+            """
+        Then the response status should be 201
+        Then the mock API should have received GET "/api/sites" 1 time
+        Then the mock API should not have received DELETE "/api/sites"
+        Then the mock API should have received POST "/api/sites" with the headers:
+            | Authorization | Bearer peach |
+        Then the mock API should have received POST "/api/sites" with the JSON values:
+            | site.name | pear |
+
+    Scenario: Unknown mock API routes are not found
+        When I request GET "{{humane_mock_url}}/api/unknown"
+        Then the response status should be 404
+        Then the response body should contain "No mock route for GET /api/unknown"
+        Then the mock API should have received GET "/api/unknown"
@@ -0,0 +1,29 @@
+Feature: Scenario Outlines
+
+    Scenario Outline: Outlines expand into docstrings and tables
+        Given I have a "public/<page>.html" file with the content:
+            """
+            <h1><title></h1>
+            <p>Served from {{humane_temp_dir}}</p>
+            """
+        Then I should see "<h1><title></h1>" in "public/<page>.html"
+        Then I should see a selector "h1" in "public/<page>.html" with the attributes:
+            | innerText | <title> |
+
+        Examples:
+            | page  | title |
+            | peach | Peach |
+            | pear  | Pear  |
+
+    Scenario Outline: Substitutions can read example columns
+        Given I have a "<file>" file with the content:
+            """
+            {{example:greeting}} from {{example:file}}
+            """
+        When I run "cat <file>"
+        Then I should see "<greeting> from <file>" in stdout
+
+        Examples:
+            | file      | greeting |
+            | hello.txt | Hello    |
+            | dir/b.txt | Howdy    |
@@ -0,0 +1,19 @@
+Feature: Script Steps
+
+    Scenario: Script steps run with their captures as arguments
+        Given I have a "notes.txt" file with the content:
+            """
+            one
+            two
+            three
+            """
+        Then the file "notes.txt" should have 3 words
+        Then I should see "notes.txt has 3 words" in stdout
+
+    Scenario: Script steps substitute their captures
+        Given the variable "file" is "notes.txt"
+        Given I have a "notes.txt" file with the content:
+            """
+            one
+            """
+        Then DEBUG the file "{{file}}" should have 1 words
@@ -0,0 +1,8 @@
+#!/bin/sh
+# Usage: word_count.sh FILE EXPECTED
+words=$(wc -w < "$1" | tr -d ' ')
+echo "$1 has $words words"
+if [ "$words" != "$2" ]; then
+    echo "Expected $2 words" >&2
+    exit 1
+fi
@@ -0,0 +1,36 @@
+Feature: Variables
+
+    Scenario: Variables can be set and substituted
+        Given the variable "site" is "peach"
+        Given the variable "output" is "{{site}}-site"
+        Given I have a "{{output}}/index.html" file with the content:
+            """
+            <h1>{{site}}</h1>
+            <p>{{ page.title }}</p>
+            """
+        Then I should see "<h1>peach</h1>" in "peach-site/index.html"
+        Then I should see "{{ page.title }}" in "peach-site/index.html"
+        Then I should see a selector "h1" in "{{output}}/index.html" with the attributes:
+            | innerText | {{site}} |
+
+    Scenario: Environment variables can be substituted
+        Given I have the environment variables:
+            | GREETING | Hello |
+        When I run "echo {{env:GREETING}}"
+        Then I should see "Hello" in stdout
+
+    Scenario: Command output can be saved as a variable
+        When I run "echo 'Created site id: abc123'"
+        When I save stdout matching /id: (\w+)/ as "id"
+        When I run "echo Deleting {{id}}"
+        Then I should see "Deleting abc123" in stdout
+        Then I should see "{{id}}" in stdout
+
+    Scenario: Unknown substitutions in file contents are left as written
+        Given I have a "templates/env.txt" file with the content:
+            """
+            Token: {{env:HUMANE_UNSET_VARIABLE}}
+            Title: {{example:title}}
+            """
+        Then I should see "Token: {{env:HUMANE_UNSET_VARIABLE" in "templates/env.txt"
+        Then I should see "Title: {{example:title" in "templates/env.txt"
@@ -0,0 +1,12 @@
+Feature: Visual Tests
+
+    Scenario: Pages can be compared with a baseline screenshot
+        Given I have a "public/index.html" file with the content:
+            """
+            <style>html, body { margin: 0; height: 100%; background: #3366cc; }</style>
+            """
+        When I serve the "public" directory
+        Given the viewport is 200x100
+        When I load "/"
+        Then the page should look like "features/baselines/solid.png"
+        Then the page should look like "features/baselines/solid.png" within 0.5%
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use chromiumoxide::cdp::browser_protocol::accessibility::{
+    AxNode, AxNodeId, AxValue, EnableParams, GetFullAxTreeParams,
+};
+use chromiumoxide::cdp::browser_protocol::dom::GetOuterHtmlParams;
+
+use super::BrowserTester;
+
+/// Longest snippet of a violating node's HTML to show in a report
+const SNIPPET_LENGTH: usize = 120;
+
+/// Checks run against each node of the page's accessibility tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessibilityRule {
+    DocumentTitle,
+    ImageAlt,
+    ButtonName,
+    LinkName,
+    Label,
+    EmptyHeading,
+}
+
+impl AccessibilityRule {
+    pub const ALL: [AccessibilityRule; 6] = [
+        AccessibilityRule::DocumentTitle,
+        AccessibilityRule::ImageAlt,
+        AccessibilityRule::ButtonName,
+        AccessibilityRule::LinkName,
+        AccessibilityRule::Label,
+        AccessibilityRule::EmptyHeading,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AccessibilityRule::DocumentTitle => "document-title",
+            AccessibilityRule::ImageAlt => "image-alt",
+            AccessibilityRule::ButtonName => "button-name",
+            AccessibilityRule::LinkName => "link-name",
+            AccessibilityRule::Label => "label",
+            AccessibilityRule::EmptyHeading => "empty-heading",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            AccessibilityRule::DocumentTitle => "The document has no title",
+            AccessibilityRule::ImageAlt => "Image has no alternative text",
+            AccessibilityRule::ButtonName => "Button has no accessible name",
+            AccessibilityRule::LinkName => "Link has no accessible name",
+            AccessibilityRule::Label => "Form field has no label",
+            AccessibilityRule::EmptyHeading => "Heading has no text",
+        }
+    }
+
+    /// Every rule requires its nodes to have an accessible name,
+    /// so a rule only needs to say which roles it covers
+    fn covers(&self, role: &str) -> bool {
+        match self {
+            AccessibilityRule::DocumentTitle => role == "RootWebArea",
+            AccessibilityRule::ImageAlt => matches!(role, "img" | "image"),
+            AccessibilityRule::ButtonName => role == "button",
+            AccessibilityRule::LinkName => role == "link",
+            AccessibilityRule::Label => matches!(
+                role,
+                "textbox"
+                    | "searchbox"
+                    | "combobox"
+                    | "checkbox"
+                    | "radio"
+                    | "slider"
+                    | "spinbutton"
+                    | "listbox"
+                    | "switch"
+            ),
+            AccessibilityRule::EmptyHeading => role == "heading",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|rule| rule.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|rule| rule.name()).collect();
+                format!(
+                    "Unknown accessibility rule {:?}. Available rules: {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// A node of the page that breaks an accessibility rule
+#[derive(Debug)]
+pub struct AccessibilityViolation {
+    pub rule: AccessibilityRule,
+    pub role: String,
+    pub html: Option<String>,
+}
+
+impl fmt::Display for AccessibilityViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {} ({})",
+            self.rule.name(),
+            self.rule.description(),
+            self.role
+        )?;
+        if let Some(html) = &self.html {
+            write!(f, "\n    {}", html)?;
+        }
+        Ok(())
+    }
+}
+
+impl BrowserTester {
+    /// Audits the active page's accessibility tree, or the part of it within `scope`
+    pub async fn accessibility_violations(
+        &mut self,
+        scope: Option<&str>,
+        rules: &[AccessibilityRule],
+    ) -> Result<Vec<AccessibilityViolation>, Box<dyn std::error::Error>> {
+        self.page().execute(EnableParams::default()).await?;
+        let nodes = self
+            .page()
+            .execute(GetFullAxTreeParams::default())
+            .await?
+            .result
+            .nodes;
+
+        let audited: Vec<&AxNode> = match scope {
+            None => nodes.iter().collect(),
+            Some(selector) => {
+                let element = self.page().find_element(selector).await?;
+                let root = nodes
+                    .iter()
+                    .find(|node| {
+                        node.backend_dom_node_id.as_ref() == Some(&element.backend_node_id)
+                    })
+                    .ok_or_else(|| format!("{} is not part of the accessibility tree", selector))?;
+                descendants(&nodes, root)
+            }
+        };
+
+        let mut violations = vec![];
+        for node in audited {
+            if node.ignored {
+                continue;
+            }
+            let role = ax_string(&node.role);
+            let Some(rule) = rules.iter().find(|rule| rule.covers(&role)) else {
+                continue;
+            };
+            if !ax_string(&node.name).trim().is_empty() {
+                continue;
+            }
+
+            let html = match &node.backend_dom_node_id {
+                Some(id) => self
+                    .page()
+                    .execute(GetOuterHtmlParams::builder().backend_node_id(*id).build())
+                    .await
+                    .ok()
+                    .map(|html| snippet(&html.result.outer_html)),
+                None => None,
+            };
+            violations.push(AccessibilityViolation {
+                rule: *rule,
+                role,
+                html,
+            });
+        }
+        Ok(violations)
+    }
+}
+
+/// The node and everything beneath it in the accessibility tree
+fn descendants<'a>(nodes: &'a [AxNode], root: &'a AxNode) -> Vec<&'a AxNode> {
+    let by_id: HashMap<&AxNodeId, &AxNode> =
+        nodes.iter().map(|node| (&node.node_id, node)).collect();
+    let mut found = vec![];
+    let mut queue = vec![root];
+    while let Some(node) = queue.pop() {
+        found.push(node);
+        for child in node.child_ids.iter().flatten() {
+            if let Some(child) = by_id.get(child) {
+                queue.push(child);
+            }
+        }
+    }
+    found
+}
+
+fn ax_string(value: &Option<AxValue>) -> String {
+    value
+        .as_ref()
+        .and_then(|value| value.value.as_ref())
+        .and_then(|value| value.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// The opening of a node's HTML, which is plenty to find it by
+fn snippet(html: &str) -> String {
+    let html = html.split_whitespace().collect::<Vec<_>>().join(" ");
+    if html.chars().count() <= SNIPPET_LENGTH {
+        return html;
+    }
+    format!("{}…", html.chars().take(SNIPPET_LENGTH).collect::<String>())
+}
@@ -0,0 +1,306 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use chromiumoxide::cdp::js_protocol::debugger::{
+    self, EventScriptParsed, GetScriptSourceParams, SetSkipAllPausesParams,
+};
+use chromiumoxide::cdp::js_protocol::profiler::{
+    self, FunctionCoverage, StartPreciseCoverageParams, TakePreciseCoverageParams,
+};
+use chromiumoxide::cdp::js_protocol::runtime::ScriptId;
+use chromiumoxide::page::Page;
+use futures::StreamExt;
+use serde_json::{json, Map, Value};
+use tokio::task::JoinHandle;
+
+use super::{BrowserTester, Tab};
+
+/// Coverage from every scenario's pages, merged by the script's report path
+static REPORT: OnceLock<Mutex<CoverageReport>> = OnceLock::new();
+
+/// The sources of the scripts a tab has parsed, by script ID.
+/// Sources are read as scripts are parsed, since they can't be read once their page is gone.
+pub(super) type ScriptSources = Arc<Mutex<HashMap<ScriptId, ScriptSource>>>;
+
+#[derive(Debug)]
+pub(super) struct ScriptSource {
+    url: String,
+    source: String,
+}
+
+#[derive(Debug, Default)]
+struct CoverageReport {
+    files: BTreeMap<String, FileCoverage>,
+}
+
+/// Execution counts by 1-based line number, and by function
+#[derive(Debug, Default)]
+struct FileCoverage {
+    lines: BTreeMap<usize, i64>,
+    functions: BTreeMap<(usize, String), i64>,
+}
+
+impl CoverageReport {
+    fn add(&mut self, path: String, script: &ScriptSource, functions: &[FunctionCoverage]) {
+        // V8 reports offsets in UTF-16 code units
+        let source: Vec<u16> = script.source.encode_utf16().collect();
+        let mut counts = vec![0; source.len()];
+
+        // Each function's first range spans the function, and the ranges after it are the blocks within.
+        // Applying enclosing ranges before the ranges they contain leaves every unit with its innermost count.
+        let mut functions: Vec<_> = functions.iter().filter(|f| !f.ranges.is_empty()).collect();
+        functions.sort_by_key(|f| (f.ranges[0].start_offset, -f.ranges[0].end_offset));
+        for function in &functions {
+            for range in &function.ranges {
+                let start = (range.start_offset.max(0) as usize).min(source.len());
+                let end = (range.end_offset.max(0) as usize).min(source.len());
+                counts[start..end].fill(range.count);
+            }
+        }
+
+        let file = self.files.entry(path).or_default();
+        let mut line_starts = vec![0];
+        let mut line_start = 0;
+        for (line, text) in source.split(|unit| *unit == u16::from(b'\n')).enumerate() {
+            let line_end = line_start + text.len();
+            // A line only counts as run when all of its code did
+            let count = (line_start..line_end)
+                .filter(|i| !char::from_u32(source[*i] as u32).is_some_and(char::is_whitespace))
+                .map(|i| counts[i])
+                .min();
+            if let Some(count) = count {
+                *file.lines.entry(line + 1).or_default() += count;
+            }
+            line_start = line_end + 1;
+            line_starts.push(line_start);
+        }
+
+        for function in functions {
+            let range = &function.ranges[0];
+            // The script's top level is reported as a nameless function spanning the whole script
+            if function.function_name.is_empty() && range.start_offset == 0 {
+                continue;
+            }
+            let line = line_starts.partition_point(|start| *start <= range.start_offset as usize);
+            let name = match function.function_name.as_str() {
+                "" => format!("(anonymous_{})", line),
+                name => name.to_string(),
+            };
+            *file.functions.entry((line, name)).or_default() += range.count;
+        }
+    }
+
+    fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (path, file) in &self.files {
+            lcov.push_str(&format!("TN:\nSF:{}\n", path));
+            for (line, name) in file.functions.keys() {
+                lcov.push_str(&format!("FN:{},{}\n", line, name));
+            }
+            for ((_, name), count) in &file.functions {
+                lcov.push_str(&format!("FNDA:{},{}\n", count, name));
+            }
+            lcov.push_str(&format!(
+                "FNF:{}\nFNH:{}\n",
+                file.functions.len(),
+                file.functions.values().filter(|c| **c > 0).count()
+            ));
+            for (line, count) in &file.lines {
+                lcov.push_str(&format!("DA:{},{}\n", line, count));
+            }
+            lcov.push_str(&format!(
+                "LF:{}\nLH:{}\nend_of_record\n",
+                file.lines.len(),
+                file.lines.values().filter(|c| **c > 0).count()
+            ));
+        }
+        lcov
+    }
+
+    /// Istanbul's coverage JSON, with a statement per line
+    fn to_istanbul(&self) -> Value {
+        let mut report = Map::new();
+        for (path, file) in &self.files {
+            let mut statement_map = Map::new();
+            let mut statements = Map::new();
+            for (i, (line, count)) in file.lines.iter().enumerate() {
+                statement_map.insert(i.to_string(), location(*line));
+                statements.insert(i.to_string(), json!(count));
+            }
+
+            let mut fn_map = Map::new();
+            let mut functions = Map::new();
+            for (i, ((line, name), count)) in file.functions.iter().enumerate() {
+                fn_map.insert(
+                    i.to_string(),
+                    json!({
+                        "name": name,
+                        "decl": location(*line),
+                        "loc": location(*line),
+                        "line": line,
+                    }),
+                );
+                functions.insert(i.to_string(), json!(count));
+            }
+
+            report.insert(
+                path.clone(),
+                json!({
+                    "path": path,
+                    "statementMap": statement_map,
+                    "s": statements,
+                    "fnMap": fn_map,
+                    "f": functions,
+                    "branchMap": {},
+                    "b": {},
+                }),
+            );
+        }
+        Value::Object(report)
+    }
+}
+
+fn location(line: usize) -> Value {
+    json!({
+        "start": { "line": line, "column": 0 },
+        "end": { "line": line, "column": null },
+    })
+}
+
+/// Scripts are reported by their host and path, so that same-named scripts from different servers stay apart.
+/// Humane's own servers get a new port each scenario, so they're reported by name instead:
+/// the default server's scripts by their path alone, and a named server's under its name.
+fn report_path(url: &str, server_names: &HashMap<u16, String>) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let rest = rest.split(['?', '#']).next().unwrap_or(rest);
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let server = host
+        .strip_prefix("localhost:")
+        .or_else(|| host.strip_prefix("127.0.0.1:"))
+        .and_then(|port| port.parse().ok())
+        .and_then(|port: u16| server_names.get(&port));
+    match server.map(String::as_str) {
+        Some("") => path.to_string(),
+        Some(name) => format!("{}/{}", name, path),
+        None => format!("{}/{}", host, path),
+    }
+}
+
+/// Starts collecting precise coverage for the page's scripts
+pub(super) async fn start(
+    page: &Page,
+) -> Result<(ScriptSources, JoinHandle<Result<(), std::io::Error>>), Box<dyn std::error::Error>> {
+    let mut parsed = page.event_listener::<EventScriptParsed>().await?;
+    page.execute(debugger::EnableParams::default()).await?;
+    // Enabling the debugger would otherwise stop pages at any `debugger` statements
+    page.execute(SetSkipAllPausesParams::new(true)).await?;
+    page.execute(profiler::EnableParams::default()).await?;
+    page.execute(
+        StartPreciseCoverageParams::builder()
+            .call_count(true)
+            .detailed(true)
+            .build(),
+    )
+    .await?;
+
+    let sources: ScriptSources = Arc::new(Mutex::new(HashMap::new()));
+    let scripts = Arc::clone(&sources);
+    let page = page.clone();
+    let thread = tokio::task::spawn(async move {
+        while let Some(event) = parsed.next().await {
+            // Inline scripts share their page's URL, so only script files are covered
+            if !event.url.starts_with("http") || event.start_line != 0 || event.start_column != 0 {
+                continue;
+            }
+            if let Ok(source) = page
+                .execute(GetScriptSourceParams::new(event.script_id.clone()))
+                .await
+            {
+                scripts.lock().unwrap().insert(
+                    event.script_id.clone(),
+                    ScriptSource {
+                        url: event.url.clone(),
+                        source: source.result.script_source,
+                    },
+                );
+            }
+        }
+        Ok(())
+    });
+    Ok((sources, thread))
+}
+
+impl BrowserTester {
+    /// Adds the coverage of every open tab to the run's report
+    pub(super) async fn collect_coverage(&self) -> Result<(), Box<dyn std::error::Error>> {
+        for tab in self.tabs.values() {
+            self.collect_tab_coverage(tab).await?;
+        }
+        Ok(())
+    }
+
+    /// Adds the active tab's coverage to the run's report before it navigates,
+    /// since its scripts' coverage can't be taken once their page is gone
+    pub(super) async fn collect_active_coverage(&self) {
+        if !self.coverage {
+            return;
+        }
+        if let Some(tab) = self
+            .active_tab
+            .as_ref()
+            .and_then(|name| self.tabs.get(name))
+        {
+            if let Err(e) = self.collect_tab_coverage(tab).await {
+                eprintln!("JavaScript coverage could not be collected: {}", e);
+            }
+        }
+    }
+
+    /// Taking coverage resets its counts, so each call adds only what ran since the last
+    async fn collect_tab_coverage(&self, tab: &Tab) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(sources) = &tab.scripts else {
+            return Ok(());
+        };
+        let coverage = tab
+            .page
+            .execute(TakePreciseCoverageParams::default())
+            .await?
+            .result
+            .result;
+
+        let sources = sources.lock().unwrap();
+        let mut report = REPORT.get_or_init(Default::default).lock().unwrap();
+        for script in coverage {
+            if let Some(source) = sources.get(&script.script_id) {
+                let path = report_path(&source.url, &self.server_names);
+                report.add(path, source, &script.functions);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the run's coverage as LCOV, or as Istanbul JSON for `.json` files
+pub fn write_report(path: &Path) {
+    let Some(report) = REPORT.get() else {
+        println!("No JavaScript coverage was collected");
+        return;
+    };
+    let report = report.lock().unwrap();
+    let contents = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::to_string_pretty(&report.to_istanbul()).unwrap(),
+        _ => report.to_lcov(),
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).unwrap();
+    }
+    fs::write(path, contents)
+        .unwrap_or_else(|e| panic!("Coverage could not be written to {:?}: {}", path, e));
+    println!(
+        "Wrote JavaScript coverage for {} script(s) to {:?}",
+        report.files.len(),
+        path
+    );
+}
@@ -0,0 +1,868 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// use chromiumoxide::cdp::browser_protocol::log::EventEntryAdded;
+use futures::StreamExt;
+
+use chromiumoxide::browser::Browser;
+use chromiumoxide::cdp::browser_protocol::browser::BrowserContextId;
+use chromiumoxide::cdp::browser_protocol::emulation::{
+    MediaFeature, SetDeviceMetricsOverrideParams, SetEmulatedMediaParams,
+    SetTouchEmulationEnabledParams, SetUserAgentOverrideParams,
+};
+use chromiumoxide::cdp::browser_protocol::page::{
+    CaptureScreenshotFormat, EventFrameNavigated, GetNavigationHistoryParams,
+    NavigateToHistoryEntryParams, ReloadParams,
+};
+use chromiumoxide::cdp::browser_protocol::security::SetIgnoreCertificateErrorsParams;
+use chromiumoxide::cdp::browser_protocol::target::{
+    CreateBrowserContextParams, CreateTargetParams, DisposeBrowserContextParams,
+};
+use chromiumoxide::cdp::js_protocol::runtime::{CallArgument, CallFunctionOnParams};
+use chromiumoxide::listeners::EventStream;
+use chromiumoxide::page::{Page, ScreenshotParams};
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+
+use super::{DEFAULT_WAIT_TIMEOUT, POLL_INTERVAL};
+use crate::options::HumaneBrowserConfig;
+
+pub use accessibility::AccessibilityRule;
+pub use coverage::write_report as write_coverage;
+use coverage::ScriptSources;
+use network::{InterceptRule, NetworkActivity};
+pub use network::{Interception, RecordedRequest, RequestFilter, UrlPattern};
+use storage::PendingState;
+pub use storage::WebStorage;
+
+pub use pool::close_all;
+
+mod accessibility;
+mod coverage;
+mod network;
+mod performance;
+mod pool;
+mod storage;
+
+/// The tab that `I load` opens when no tabs are open yet
+const DEFAULT_TAB: &str = "main";
+
+const IS_VISIBLE: &str = "const isVisible = (el) => !!el
+    && !!(el.offsetWidth || el.offsetHeight || el.getClientRects().length)
+    && getComputedStyle(el).visibility !== 'hidden';";
+
+const CONSOLE_OVERRIDE: &str = "function() {
+    const c = console; c.events = [];
+    let l = [c.log, c.warn, c.error, c.debug].map(e => e.bind(c));
+    let p = (m, a) => c.events.push(`${m}: ${Array.from(a).join(' ')}`)
+    c.log = function(){ l[0].apply(c, arguments); p('LOG', arguments); }
+    c.warn = function(){ l[1].apply(c, arguments); p('WRN', arguments); }
+    c.error = function(){ l[2].apply(c, arguments); p('ERR', arguments); }
+    c.debug = function(){ l[3].apply(c, arguments); p('DBG', arguments); }
+}";
+
+#[derive(Debug)]
+pub struct BrowserTester {
+    browser: Arc<Browser>,
+    context: BrowserContextId,
+    tabs: HashMap<String, Tab>,
+    active_tab: Option<String>,
+    log_events: Arc<Mutex<Vec<String>>>,
+    slow_mo: Option<Duration>,
+    wait_timeout: u64,
+    emulation: Emulation,
+    interceptions: Arc<Mutex<Vec<InterceptRule>>>,
+    pending: PendingState,
+    coverage: bool,
+    /// The names of the scenario's servers by port, for reporting coverage
+    server_names: HashMap<u16, String>,
+    ignore_certificate_errors: bool,
+    threads: Vec<JoinHandle<Result<(), std::io::Error>>>,
+}
+
+/// States of the page that web steps can wait for
+#[derive(Debug)]
+pub enum WaitFor {
+    Attached(String),
+    Visible(String),
+    /// Present on the page but not visible
+    Hidden(String),
+    /// Not visible, whether or not it's on the page
+    HiddenOrMissing(String),
+    Detached(String),
+    Text(String),
+    Truthy(String),
+    NetworkIdle,
+}
+
+impl WaitFor {
+    /// A JS function call that returns whether the page has reached this state,
+    /// or `None` for states tracked outside of the page
+    fn script(&self) -> Option<CallFunctionOnParams> {
+        let script = match self {
+            WaitFor::Attached(selector) => js_call(
+                "(selector) => !!document.querySelector(selector)",
+                [json!(selector)],
+            ),
+            WaitFor::Visible(selector) => js_call(
+                &format!(
+                    "(selector) => {{ {} return isVisible(document.querySelector(selector)); }}",
+                    IS_VISIBLE
+                ),
+                [json!(selector)],
+            ),
+            WaitFor::Hidden(selector) => js_call(
+                &format!(
+                    "(selector) => {{ {} const el = document.querySelector(selector); return !!el && !isVisible(el); }}",
+                    IS_VISIBLE
+                ),
+                [json!(selector)],
+            ),
+            WaitFor::HiddenOrMissing(selector) => js_call(
+                &format!(
+                    "(selector) => {{ {} return !isVisible(document.querySelector(selector)); }}",
+                    IS_VISIBLE
+                ),
+                [json!(selector)],
+            ),
+            WaitFor::Detached(selector) => js_call(
+                "(selector) => !document.querySelector(selector)",
+                [json!(selector)],
+            ),
+            WaitFor::Text(text) => js_call(
+                "(text) => !!document.body && document.body.innerText.includes(text)",
+                [json!(text)],
+            ),
+            // The user's own function is the script, so it has nothing to pass in
+            WaitFor::Truthy(function) => {
+                js_call(&format!("async () => !!(await ({})())", function), [])
+            }
+            WaitFor::NetworkIdle => return None,
+        };
+        Some(script)
+    }
+}
+
+impl fmt::Display for WaitFor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaitFor::Attached(selector) => write!(f, "{} to exist", selector),
+            WaitFor::Visible(selector) => write!(f, "{} to be visible", selector),
+            WaitFor::Hidden(selector) => write!(f, "{} to be hidden", selector),
+            WaitFor::HiddenOrMissing(selector) => {
+                write!(f, "{} to be hidden or not exist", selector)
+            }
+            WaitFor::Detached(selector) => write!(f, "{} to be detached", selector),
+            WaitFor::Text(text) => write!(f, "the text {:?} to appear", text),
+            WaitFor::Truthy(_) => write!(f, "the script to return a truthy value"),
+            WaitFor::NetworkIdle => write!(f, "the network to be idle"),
+        }
+    }
+}
+
+/// Properties of the page's elements that web steps can assert on.
+/// Apart from `Count`, these read the first element matching a selector
+/// and are `null` when there is no such element.
+#[derive(Debug)]
+pub enum ElementProperty {
+    Text,
+    Html,
+    Attribute(String),
+    Style(String),
+    Visible,
+    Focused,
+    Count,
+}
+
+impl ElementProperty {
+    fn key(&self) -> (&'static str, Option<&str>) {
+        match self {
+            ElementProperty::Text => ("text", None),
+            ElementProperty::Html => ("html", None),
+            ElementProperty::Attribute(name) => ("attribute", Some(name)),
+            ElementProperty::Style(name) => ("style", Some(name)),
+            ElementProperty::Visible => ("visible", None),
+            ElementProperty::Focused => ("focused", None),
+            ElementProperty::Count => ("count", None),
+        }
+    }
+}
+
+impl fmt::Display for ElementProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElementProperty::Text => write!(f, "the text of"),
+            ElementProperty::Html => write!(f, "the HTML of"),
+            ElementProperty::Attribute(name) => write!(f, "the {} attribute of", name),
+            ElementProperty::Style(name) => write!(f, "the computed {} style of", name),
+            ElementProperty::Visible => write!(f, "the visibility of"),
+            ElementProperty::Focused => write!(f, "the focus of"),
+            ElementProperty::Count => write!(f, "the number of elements matching"),
+        }
+    }
+}
+
+/// The outcome of a wait that never saw an acceptable value
+#[derive(Debug)]
+pub struct WaitTimeout<T> {
+    pub waited: Duration,
+    pub last_value: Option<T>,
+    pub last_error: Option<String>,
+}
+
+/// A named page, tracking its own network activity
+#[derive(Debug)]
+struct Tab {
+    page: Page,
+    network: Arc<Mutex<NetworkActivity>>,
+    intercepting: bool,
+    /// Present when collecting JavaScript coverage
+    scripts: Option<ScriptSources>,
+}
+
+/// Device and media settings applied to every page the browser opens
+#[derive(Debug, Default)]
+struct Emulation {
+    viewport: Option<(u32, u32)>,
+    mobile_user_agent: Option<String>,
+    media_features: Vec<MediaFeature>,
+}
+
+impl Emulation {
+    async fn apply(&self, page: &Page) -> Result<(), Box<dyn std::error::Error>> {
+        let mobile = self.mobile_user_agent.is_some();
+        if let Some((width, height)) = self.viewport {
+            let scale = if mobile { 3 } else { 1 };
+            page.execute(SetDeviceMetricsOverrideParams::new(
+                width, height, scale, mobile,
+            ))
+            .await?;
+        }
+        if let Some(user_agent) = &self.mobile_user_agent {
+            page.execute(SetTouchEmulationEnabledParams::new(true))
+                .await?;
+            page.execute(SetUserAgentOverrideParams::new(user_agent))
+                .await?;
+        }
+        if !self.media_features.is_empty() {
+            page.execute(
+                SetEmulatedMediaParams::builder()
+                    .features(self.media_features.clone())
+                    .build(),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for BrowserTester {
+    fn drop(&mut self) {
+        for thread in &self.threads {
+            thread.abort()
+        }
+    }
+}
+
+impl BrowserTester {
+    /// Opens a fresh incognito context in one of the run's shared browsers,
+    /// so that no cookies, storage or cache leak between scenarios
+    pub async fn new(options: &HumaneBrowserConfig) -> Self {
+        let browser = pool::acquire(options).await;
+        let context = browser
+            .execute(CreateBrowserContextParams::default())
+            .await
+            .unwrap_or_else(|e| panic!("Browser context could not be created: {}", e))
+            .result
+            .browser_context_id;
+
+        Self {
+            browser,
+            context,
+            tabs: HashMap::new(),
+            active_tab: None,
+            log_events: Arc::new(Mutex::new(Vec::new())),
+            slow_mo: options.slow_mo.map(Duration::from_millis),
+            wait_timeout: options.wait_timeout.unwrap_or(DEFAULT_WAIT_TIMEOUT),
+            emulation: Emulation::default(),
+            interceptions: Arc::new(Mutex::new(Vec::new())),
+            pending: PendingState::default(),
+            coverage: options.coverage.is_some(),
+            server_names: HashMap::new(),
+            ignore_certificate_errors: false,
+            threads: vec![],
+        }
+    }
+
+    /// Disposes of the scenario's browser context, closing all of its tabs
+    pub async fn close(&mut self) {
+        if self.coverage {
+            if let Err(e) = self.collect_coverage().await {
+                eprintln!("JavaScript coverage could not be collected: {}", e);
+            }
+        }
+        for thread in &self.threads {
+            thread.abort()
+        }
+        self.tabs.clear();
+        self.active_tab = None;
+        let _ = self
+            .browser
+            .execute(DisposeBrowserContextParams::new(self.context.clone()))
+            .await;
+    }
+
+    fn tab(&self) -> &Tab {
+        let name = self.active_tab.as_ref().expect("No page launched");
+        &self.tabs[name]
+    }
+
+    fn page(&self) -> &Page {
+        &self.tab().page
+    }
+
+    /// Navigates the active tab, opening the first tab if there isn't one yet
+    pub async fn load_page(&mut self, url: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if self.active_tab.is_none() {
+            return self.open_tab(DEFAULT_TAB, url).await;
+        }
+
+        self.collect_active_coverage().await;
+        self.page().goto(url).await?;
+        self.slow_down().await;
+        Ok(())
+    }
+
+    /// Tells the browser which ports the scenario's servers are on, with the default server as an empty name
+    pub fn name_servers(&mut self, server_names: HashMap<u16, String>) {
+        self.server_names = server_names;
+    }
+
+    /// Accepts any certificate in current and future tabs, such as the run's self-signed one for HTTPS servers
+    pub async fn ignore_certificate_errors(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.ignore_certificate_errors = true;
+        for tab in self.tabs.values() {
+            tab.page
+                .execute(SetIgnoreCertificateErrorsParams::new(true))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Opens a new named tab at the given URL and makes it the active tab
+    pub async fn open_tab(
+        &mut self,
+        name: &str,
+        url: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.tabs.contains_key(name) {
+            return Err(format!("A tab named {:?} is already open", name).into());
+        }
+
+        let page = self
+            .browser
+            .new_page(
+                CreateTargetParams::builder()
+                    .url("about:blank")
+                    .browser_context_id(self.context.clone())
+                    .build()?,
+            )
+            .await?;
+        self.emulation.apply(&page).await?;
+        if self.ignore_certificate_errors {
+            page.execute(SetIgnoreCertificateErrorsParams::new(true))
+                .await?;
+        }
+        page.evaluate_on_new_document(format!("({})()", CONSOLE_OVERRIDE))
+            .await?;
+
+        let (network, threads) = network::record(&page).await?;
+        self.threads.extend(threads);
+        let scripts = if self.coverage {
+            let (scripts, thread) = coverage::start(&page).await?;
+            self.threads.push(thread);
+            Some(scripts)
+        } else {
+            None
+        };
+        let intercepting = !self.interceptions.lock().unwrap().is_empty();
+        if intercepting {
+            let rules = Arc::clone(&self.interceptions);
+            self.threads.push(network::intercept(&page, rules).await?);
+        }
+
+        // TODO: This block isn't working
+        // https://github.com/mattsse/chromiumoxide/issues/91
+        let mut events = page
+            .event_listener::<chromiumoxide::cdp::browser_protocol::log::EventEntryAdded>()
+            .await?;
+
+        let event_list = Arc::clone(&self.log_events);
+        self.threads.push(tokio::task::spawn(async move {
+            let event = events.next().await;
+            if let Some(event) = event {
+                event_list.lock().unwrap().push(format!("{:#?}", event));
+            }
+            panic!("This block was broken, but now seems to be working? Remove the console override hack 🙂 ");
+        }));
+        // END TODO
+
+        self.tabs.insert(
+            name.into(),
+            Tab {
+                page,
+                network,
+                intercepting,
+                scripts,
+            },
+        );
+        self.active_tab = Some(name.into());
+
+        let seeded = self.seed_pending_state(url).await?;
+        self.page().goto(url).await?;
+        self.finish_seeding(seeded).await?;
+        self.slow_down().await;
+        Ok(())
+    }
+
+    pub async fn switch_tab(&mut self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let tab = self.tabs.get(name).ok_or_else(|| {
+            let mut open: Vec<_> = self.tabs.keys().collect();
+            open.sort();
+            format!("No tab named {:?} is open. Open tabs: {:?}", name, open)
+        })?;
+        tab.page.bring_to_front().await?;
+        self.active_tab = Some(name.into());
+        Ok(())
+    }
+
+    /// Moves through the active tab's history, e.g. `-1` to go back
+    pub async fn go_through_history(
+        &mut self,
+        offset: i64,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let history = self
+            .page()
+            .execute(GetNavigationHistoryParams::default())
+            .await?
+            .result;
+        let entry = usize::try_from(history.current_index + offset)
+            .ok()
+            .and_then(|index| history.entries.get(index))
+            .ok_or_else(|| {
+                format!(
+                    "There is no page {} in the history",
+                    if offset < 0 { "behind" } else { "ahead" }
+                )
+            })?;
+
+        let navigation = self.expect_navigation().await?;
+        self.page()
+            .execute(NavigateToHistoryEntryParams::new(entry.id))
+            .await?;
+        self.finish_navigation(navigation, step_timeout).await
+    }
+
+    pub async fn reload(
+        &mut self,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let navigation = self.expect_navigation().await?;
+        self.page().execute(ReloadParams::default()).await?;
+        self.finish_navigation(navigation, step_timeout).await
+    }
+
+    pub async fn click_and_wait_for_navigation(
+        &mut self,
+        selector: &str,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let navigation = self.expect_navigation().await?;
+        self.click(selector, step_timeout).await?;
+        self.finish_navigation(navigation, step_timeout).await
+    }
+
+    /// Starts listening for navigations, before triggering one
+    async fn expect_navigation(
+        &self,
+    ) -> Result<EventStream<EventFrameNavigated>, Box<dyn std::error::Error>> {
+        self.collect_active_coverage().await;
+        Ok(self.page().event_listener::<EventFrameNavigated>().await?)
+    }
+
+    /// Waits for the main frame to navigate and finish loading
+    async fn finish_navigation(
+        &self,
+        mut navigation: EventStream<EventFrameNavigated>,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let timeout = self.timeout(step_timeout);
+        let page = self.page();
+        tokio::time::timeout(timeout, async move {
+            while let Some(event) = navigation.next().await {
+                if event.frame.parent_id.is_none() {
+                    break;
+                }
+            }
+            page.wait_for_navigation().await.map(|_| ())
+        })
+        .await
+        .map_err(|_| {
+            format!(
+                "Timed out after {}ms waiting for the page to navigate",
+                timeout.as_millis()
+            )
+        })??;
+
+        self.slow_down().await;
+        Ok(())
+    }
+
+    pub async fn set_viewport(
+        &mut self,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.emulation.viewport = Some((width, height));
+        self.apply_emulation().await
+    }
+
+    /// Emulates a phone-sized touch device with the browser's own user agent in its mobile form
+    pub async fn emulate_mobile(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let desktop_agent = self.browser.user_agent().await?;
+        let platform = Regex::new(r"\([^)]*\)").unwrap();
+        let mobile_agent = platform
+            .replace(&desktop_agent, "(Linux; Android 10; K)")
+            .replace(" Safari/", " Mobile Safari/");
+
+        self.emulation.mobile_user_agent = Some(mobile_agent);
+        self.emulation.viewport.get_or_insert((375, 812));
+        self.apply_emulation().await?;
+        // Chromium only picks up mobile and touch changes on a fresh load
+        for tab in self.tabs.values() {
+            tab.page.reload().await?;
+        }
+        Ok(())
+    }
+
+    /// Emulates a CSS media feature, e.g. `prefers-color-scheme: dark`
+    pub async fn set_media_feature(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.emulation.media_features.retain(|f| f.name != name);
+        self.emulation
+            .media_features
+            .push(MediaFeature::new(name, value));
+        self.apply_emulation().await
+    }
+
+    async fn apply_emulation(&self) -> Result<(), Box<dyn std::error::Error>> {
+        for tab in self.tabs.values() {
+            self.emulation.apply(&tab.page).await?;
+        }
+        Ok(())
+    }
+
+    /// Pauses after an interaction when running in slow-mo mode
+    async fn slow_down(&self) {
+        if let Some(pause) = self.slow_mo {
+            sleep(pause).await;
+        }
+    }
+
+    fn timeout(&self, step_timeout: Option<u64>) -> Duration {
+        Duration::from_millis(step_timeout.unwrap_or(self.wait_timeout))
+    }
+
+    /// Repeatedly evaluates a JS function until its result satisfies `accept`,
+    /// giving up once the step's timeout (or the configured default) has passed.
+    pub async fn wait_for_value<T: DeserializeOwned>(
+        &mut self,
+        js: CallFunctionOnParams,
+        step_timeout: Option<u64>,
+        accept: impl Fn(&T) -> bool,
+    ) -> Result<T, WaitTimeout<T>> {
+        let timeout = self.timeout(step_timeout);
+        let start = Instant::now();
+        let mut last_value = None;
+        let mut last_error = None;
+        loop {
+            let result = self
+                .page()
+                .evaluate_function(js.clone())
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|res| res.into_value::<T>().map_err(|e| e.to_string()));
+            match result {
+                Ok(value) if accept(&value) => return Ok(value),
+                Ok(value) => last_value = Some(value),
+                // The page may be mid-navigation, so errors are retried until the timeout
+                Err(error) => last_error = Some(error),
+            }
+
+            if start.elapsed() >= timeout {
+                return Err(WaitTimeout {
+                    waited: timeout,
+                    last_value,
+                    last_error,
+                });
+            }
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    pub async fn wait_for(
+        &mut self,
+        condition: &WaitFor,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let Some(script) = condition.script() else {
+            return self.wait_for_network_idle(step_timeout).await;
+        };
+
+        match self
+            .wait_for_value(script, step_timeout, |done: &bool| *done)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(timeout) => Err(format!(
+                "Timed out after {}ms waiting for {}{}",
+                timeout.waited.as_millis(),
+                condition,
+                timeout
+                    .last_error
+                    .map(|e| format!("\nLast error: {}", e))
+                    .unwrap_or_default()
+            )
+            .into()),
+        }
+    }
+
+    async fn wait_for_network_idle(
+        &mut self,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let timeout = self.timeout(step_timeout);
+        let start = Instant::now();
+        loop {
+            let in_flight = {
+                let network = self.tab().network.lock().unwrap();
+                if network.is_idle() {
+                    return Ok(());
+                }
+                network.in_flight()
+            };
+
+            if start.elapsed() >= timeout {
+                return Err(format!(
+                    "Timed out after {}ms waiting for the network to be idle, {} requests were still in flight",
+                    timeout.as_millis(),
+                    in_flight
+                )
+                .into());
+            }
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Blocks or answers requests matching the pattern, in every tab.
+    /// Later rules take precedence over earlier ones.
+    pub async fn intercept_requests(
+        &mut self,
+        pattern: UrlPattern,
+        interception: Interception,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.interceptions.lock().unwrap().push(InterceptRule {
+            pattern,
+            interception,
+        });
+        for tab in self.tabs.values_mut() {
+            if !tab.intercepting {
+                let rules = Arc::clone(&self.interceptions);
+                self.threads
+                    .push(network::intercept(&tab.page, rules).await?);
+                tab.intercepting = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Every request the active tab has made so far
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.tab().network.lock().unwrap().requests.clone()
+    }
+
+    /// Waits until the requests matching the filter satisfy `accept`
+    pub async fn wait_for_requests(
+        &mut self,
+        filter: &RequestFilter,
+        step_timeout: Option<u64>,
+        accept: impl Fn(&[RecordedRequest]) -> bool,
+    ) -> Result<Vec<RecordedRequest>, WaitTimeout<Vec<RecordedRequest>>> {
+        let timeout = self.timeout(step_timeout);
+        let start = Instant::now();
+        loop {
+            let matching: Vec<_> = self
+                .requests()
+                .into_iter()
+                .filter(|request| filter.matches(request))
+                .collect();
+            if accept(&matching) {
+                return Ok(matching);
+            }
+
+            if start.elapsed() >= timeout {
+                return Err(WaitTimeout {
+                    waited: timeout,
+                    last_value: Some(matching),
+                    last_error: None,
+                });
+            }
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    pub async fn click(
+        &mut self,
+        selector: &str,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.wait_for(&WaitFor::Visible(selector.into()), step_timeout)
+            .await?;
+
+        // Clicking can navigate away from the page
+        self.collect_active_coverage().await;
+        self.page().find_element(selector).await?.click().await?;
+
+        self.slow_down().await;
+        Ok(())
+    }
+
+    pub async fn selector_exists(
+        &mut self,
+        selector: &str,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.wait_for(&WaitFor::Attached(selector.into()), step_timeout)
+            .await
+    }
+
+    /// Reads a property of the page's elements until `accept` is satisfied by it,
+    /// describing the last value seen if it never was.
+    pub async fn expect_element<T: DeserializeOwned + fmt::Debug>(
+        &mut self,
+        selector: &str,
+        property: &ElementProperty,
+        expectation: &str,
+        step_timeout: Option<u64>,
+        accept: impl Fn(&T) -> bool,
+    ) -> Result<T, Box<dyn std::error::Error>> {
+        let (key, name) = property.key();
+        let js = js_call(
+            &format!(
+                "(selector, property, name) => {{
+                    {}
+                    if (property === 'count') return document.querySelectorAll(selector).length;
+                    const el = document.querySelector(selector);
+                    if (!el) return null;
+                    switch (property) {{
+                        case 'visible': return isVisible(el);
+                        case 'text': return el.innerText;
+                        case 'html': return el.innerHTML;
+                        case 'attribute': return el.getAttribute(name);
+                        case 'style': return getComputedStyle(el).getPropertyValue(name);
+                        case 'focused': return el === document.activeElement;
+                    }}
+                }}",
+                IS_VISIBLE
+            ),
+            [json!(selector), json!(key), json!(name)],
+        );
+
+        match self.wait_for_value(js, step_timeout, accept).await {
+            Ok(value) => Ok(value),
+            Err(WaitTimeout {
+                waited,
+                last_value: Some(value),
+                ..
+            }) => Err(format!(
+                "Expected {} {} {}, but after {}ms it was:\n-----\n{:#?}\n-----",
+                property,
+                selector,
+                expectation,
+                waited.as_millis(),
+                value
+            )
+            .into()),
+            Err(WaitTimeout {
+                waited, last_error, ..
+            }) => Err(format!(
+                "Expected {} {} {}, but it could not be read within {}ms{}",
+                property,
+                selector,
+                expectation,
+                waited.as_millis(),
+                last_error
+                    .map(|e| format!("\nLast error: {}", e))
+                    .unwrap_or_default()
+            )
+            .into()),
+        }
+    }
+
+    pub async fn eval(&mut self, js: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.collect_active_coverage().await;
+        let _ = self.page().evaluate_function(js).await?;
+        self.slow_down().await;
+        Ok(())
+    }
+
+    pub async fn screenshot(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let png = self
+            .page()
+            .screenshot(
+                ScreenshotParams::builder()
+                    .format(CaptureScreenshotFormat::Png)
+                    .build(),
+            )
+            .await?;
+        Ok(png)
+    }
+
+    pub async fn get_logs(&mut self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let res = self
+            .page()
+            .evaluate_function("() => console.events")
+            .await?
+            .into_value::<Vec<String>>();
+
+        if let Ok(logs) = res {
+            Ok(logs)
+        } else {
+            panic!("Couldn't load logs from the browser");
+        }
+
+        // TODO: This is the real method that should be working:
+        // Ok(self.log_events.lock().unwrap().iter().cloned().collect())
+    }
+}
+
+/// Builds a call to a JS function with its arguments serialised by CDP,
+/// so that values never have to be escaped into the function's source
+fn js_call(function: &str, args: impl IntoIterator<Item = Value>) -> CallFunctionOnParams {
+    let mut call = CallFunctionOnParams::new(function);
+    call.arguments = Some(
+        args.into_iter()
+            .map(|arg| CallArgument::builder().value(arg).build())
+            .collect(),
+    );
+    call
+}
@@ -0,0 +1,293 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chromiumoxide::cdp::browser_protocol::fetch::{
+    self, ContinueRequestParams, EventRequestPaused, FailRequestParams, FulfillRequestParams,
+    HeaderEntry, RequestPattern,
+};
+use chromiumoxide::cdp::browser_protocol::network::{
+    ErrorReason, EventLoadingFailed, EventLoadingFinished, EventRequestWillBeSent,
+    EventResponseReceived, RequestId,
+};
+use chromiumoxide::page::Page;
+use futures::StreamExt;
+use regex::Regex;
+use tokio::task::JoinHandle;
+
+/// How long the page must go without any requests in flight to count as idle
+const NETWORK_IDLE_TIME: Duration = Duration::from_millis(500);
+
+/// A request made by a tab, as seen by the browser
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    id: RequestId,
+    pub url: String,
+    pub method: String,
+    pub status: Option<i64>,
+    pub error: Option<String>,
+}
+
+impl fmt::Display for RecordedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.status, &self.error) {
+            (_, Some(error)) => write!(f, "{} {} (failed: {})", self.method, self.url, error),
+            (Some(status), _) => write!(f, "{} {} ({})", self.method, self.url, status),
+            (None, None) => write!(f, "{} {} (pending)", self.method, self.url),
+        }
+    }
+}
+
+/// Everything a tab has requested, and what it is still waiting on
+#[derive(Debug)]
+pub(super) struct NetworkActivity {
+    in_flight: HashSet<RequestId>,
+    last_change: Instant,
+    pub(super) requests: Vec<RecordedRequest>,
+}
+
+impl NetworkActivity {
+    fn new() -> Self {
+        Self {
+            in_flight: HashSet::new(),
+            last_change: Instant::now(),
+            requests: Vec::new(),
+        }
+    }
+
+    pub(super) fn is_idle(&self) -> bool {
+        self.in_flight.is_empty() && self.last_change.elapsed() >= NETWORK_IDLE_TIME
+    }
+
+    pub(super) fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    fn settle(&mut self, id: &RequestId) {
+        self.in_flight.remove(id);
+        self.last_change = Instant::now();
+    }
+
+    /// Redirects reuse their request's ID, so the latest request with an ID is the live one
+    fn request(&mut self, id: &RequestId) -> Option<&mut RecordedRequest> {
+        self.requests.iter_mut().rev().find(|r| &r.id == id)
+    }
+}
+
+/// A URL to match requests against. `*` matches any run of characters,
+/// and patterns starting with `/` are matched against the URL's path instead of the full URL.
+#[derive(Debug, Clone)]
+pub struct UrlPattern {
+    source: String,
+    regex: Regex,
+}
+
+impl UrlPattern {
+    pub fn new(source: &str) -> Self {
+        let regex = source
+            .split('*')
+            .map(regex::escape)
+            .collect::<Vec<_>>()
+            .join(".*");
+        Self {
+            source: source.into(),
+            regex: Regex::new(&format!("^{}$", regex)).unwrap(),
+        }
+    }
+
+    pub fn matches(&self, url: &str) -> bool {
+        if !self.source.starts_with('/') {
+            return self.regex.is_match(url);
+        }
+        let path = url
+            .split_once("://")
+            .and_then(|(_, rest)| rest.find('/').map(|i| &rest[i..]))
+            .unwrap_or(url);
+        let without_query = path.split(['?', '#']).next().unwrap_or(path);
+        self.regex.is_match(path) || self.regex.is_match(without_query)
+    }
+}
+
+impl fmt::Display for UrlPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.source)
+    }
+}
+
+/// Narrows down recorded requests for assertions
+#[derive(Debug)]
+pub struct RequestFilter {
+    pub url: UrlPattern,
+    pub method: Option<String>,
+    pub status: Option<i64>,
+}
+
+impl RequestFilter {
+    pub fn matches(&self, request: &RecordedRequest) -> bool {
+        self.url.matches(&request.url)
+            && self
+                .method
+                .iter()
+                .all(|m| m.eq_ignore_ascii_case(&request.method))
+            && self.status.iter().all(|s| request.status == Some(*s))
+    }
+}
+
+impl fmt::Display for RequestFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.url)?;
+        if let Some(method) = &self.method {
+            write!(f, " with the method {}", method)?;
+        }
+        if let Some(status) = self.status {
+            write!(f, " with the status {}", status)?;
+        }
+        Ok(())
+    }
+}
+
+/// What the browser does with a request instead of sending it to the network
+#[derive(Debug, Clone)]
+pub enum Interception {
+    Block,
+    /// Answers with exactly these headers, so anything like CORS has to be asked for
+    Respond {
+        status: i64,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct InterceptRule {
+    pub(super) pattern: UrlPattern,
+    pub(super) interception: Interception,
+}
+
+/// Records every request the page makes, and tracks which are still in flight
+pub(super) async fn record(
+    page: &Page,
+) -> Result<
+    (
+        Arc<Mutex<NetworkActivity>>,
+        Vec<JoinHandle<Result<(), std::io::Error>>>,
+    ),
+    Box<dyn std::error::Error>,
+> {
+    let network = Arc::new(Mutex::new(NetworkActivity::new()));
+    let mut started = page.event_listener::<EventRequestWillBeSent>().await?;
+    let mut responded = page.event_listener::<EventResponseReceived>().await?;
+    let mut finished = page.event_listener::<EventLoadingFinished>().await?;
+    let mut failed = page.event_listener::<EventLoadingFailed>().await?;
+    let mut threads = vec![];
+
+    let activity = Arc::clone(&network);
+    threads.push(tokio::task::spawn(async move {
+        while let Some(event) = started.next().await {
+            let mut network = activity.lock().unwrap();
+            network.in_flight.insert(event.request_id.clone());
+            network.last_change = Instant::now();
+            network.requests.push(RecordedRequest {
+                id: event.request_id.clone(),
+                url: event.request.url.clone(),
+                method: event.request.method.clone(),
+                status: None,
+                error: None,
+            });
+        }
+        Ok(())
+    }));
+    let activity = Arc::clone(&network);
+    threads.push(tokio::task::spawn(async move {
+        while let Some(event) = responded.next().await {
+            let mut network = activity.lock().unwrap();
+            if let Some(request) = network.request(&event.request_id) {
+                request.status = Some(event.response.status);
+            }
+        }
+        Ok(())
+    }));
+    let activity = Arc::clone(&network);
+    threads.push(tokio::task::spawn(async move {
+        while let Some(event) = finished.next().await {
+            activity.lock().unwrap().settle(&event.request_id);
+        }
+        Ok(())
+    }));
+    let activity = Arc::clone(&network);
+    threads.push(tokio::task::spawn(async move {
+        while let Some(event) = failed.next().await {
+            let mut network = activity.lock().unwrap();
+            network.settle(&event.request_id);
+            if let Some(request) = network.request(&event.request_id) {
+                request.error = Some(event.error_text.clone());
+            }
+        }
+        Ok(())
+    }));
+
+    Ok((network, threads))
+}
+
+/// Pauses every request the page makes, so that the rules can block or answer it
+/// before it reaches the network. Requests that no rule matches carry on untouched.
+pub(super) async fn intercept(
+    page: &Page,
+    rules: Arc<Mutex<Vec<InterceptRule>>>,
+) -> Result<JoinHandle<Result<(), std::io::Error>>, Box<dyn std::error::Error>> {
+    let mut paused = page.event_listener::<EventRequestPaused>().await?;
+    page.execute(
+        fetch::EnableParams::builder()
+            .pattern(RequestPattern::builder().url_pattern("*").build())
+            .build(),
+    )
+    .await?;
+
+    let page = page.clone();
+    Ok(tokio::task::spawn(async move {
+        while let Some(event) = paused.next().await {
+            let interception = rules
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .find(|rule| rule.pattern.matches(&event.request.url))
+                .map(|rule| rule.interception.clone());
+
+            let id = event.request_id.clone();
+            // A request can be cancelled while it's paused, which is no concern of the test
+            let _ = match interception {
+                None => page
+                    .execute(ContinueRequestParams::new(id))
+                    .await
+                    .map(|_| ()),
+                Some(Interception::Block) => page
+                    .execute(FailRequestParams::new(id, ErrorReason::BlockedByClient))
+                    .await
+                    .map(|_| ()),
+                Some(Interception::Respond {
+                    status,
+                    headers,
+                    body,
+                }) => page
+                    .execute(
+                        FulfillRequestParams::builder()
+                            .request_id(id)
+                            .response_code(status)
+                            .response_headers(
+                                headers
+                                    .into_iter()
+                                    .map(|(name, value)| HeaderEntry::new(name, value)),
+                            )
+                            .body(base64::encode(body))
+                            .build()
+                            .unwrap(),
+                    )
+                    .await
+                    .map(|_| ()),
+            };
+        }
+        Ok(())
+    }))
+}
@@ -0,0 +1,91 @@
+use chromiumoxide::cdp::browser_protocol::performance::{EnableParams, GetMetricsParams};
+use serde::Deserialize;
+
+use super::{js_call, BrowserTester, WaitTimeout};
+
+/// Reads the page's navigation and resource timings once its load event has finished
+const READ_PAGE_LOAD: &str = "() => {
+    const [navigation] = performance.getEntriesByType('navigation');
+    if (!navigation || !navigation.loadEventEnd) return null;
+    const resources = performance.getEntriesByType('resource');
+    return {
+        loadTime: navigation.loadEventEnd - navigation.startTime,
+        transferredBytes: [navigation, ...resources].reduce((total, e) => total + e.transferSize, 0),
+        requests: [navigation, ...resources].map((e) => e.name),
+    };
+}";
+
+/// How the active page's current document loaded
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageLoad {
+    /// Milliseconds from the start of the navigation to the end of the load event
+    pub load_time: f64,
+    /// Bytes transferred over the network for the document and everything it loaded
+    pub transferred_bytes: f64,
+    /// URLs requested by the document, starting with the document itself
+    pub requests: Vec<String>,
+}
+
+impl BrowserTester {
+    /// Waits for the active page to load and for its network activity to settle,
+    /// so that requests made after the load event are included
+    pub async fn page_load(
+        &mut self,
+        step_timeout: Option<u64>,
+    ) -> Result<PageLoad, Box<dyn std::error::Error>> {
+        let js = js_call(READ_PAGE_LOAD, []);
+        if let Err(WaitTimeout {
+            waited, last_error, ..
+        }) = self
+            .wait_for_value(js.clone(), step_timeout, |load: &Option<PageLoad>| {
+                load.is_some()
+            })
+            .await
+        {
+            return Err(format!(
+                "Timed out after {}ms waiting for the page to load{}",
+                waited.as_millis(),
+                last_error
+                    .map(|e| format!("\nLast error: {}", e))
+                    .unwrap_or_default()
+            )
+            .into());
+        }
+        self.wait_for_network_idle(step_timeout).await?;
+
+        let load = self
+            .page()
+            .evaluate_function(js)
+            .await?
+            .into_value::<Option<PageLoad>>()?;
+        load.ok_or_else(|| "The page navigated while its performance was measured".into())
+    }
+
+    /// Reads one of Chromium's run-time metrics for the active page, such as `JSHeapUsedSize`
+    pub async fn performance_metric(
+        &mut self,
+        name: &str,
+    ) -> Result<f64, Box<dyn std::error::Error>> {
+        self.page().execute(EnableParams::default()).await?;
+        let metrics = self
+            .page()
+            .execute(GetMetricsParams::default())
+            .await?
+            .result
+            .metrics;
+
+        match metrics.iter().find(|metric| metric.name == name) {
+            Some(metric) => Ok(metric.value),
+            None => {
+                let names: Vec<_> = metrics.iter().map(|metric| metric.name.as_str()).collect();
+                Err(format!(
+                    "The page has no metric {:?}. Available metrics: {}",
+                    name,
+                    names.join(", ")
+                )
+                .into())
+            }
+        }
+    }
+}
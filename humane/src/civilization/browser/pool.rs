@@ -0,0 +1,89 @@
+use std::sync::{Arc, OnceLock};
+
+use chromiumoxide::browser::{Browser, BrowserConfig};
+use chromiumoxide::cdp::browser_protocol::browser::CloseParams;
+use chromiumoxide::handler::viewport::Viewport;
+use futures::StreamExt;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::options::HumaneBrowserConfig;
+
+/// Chromium processes shared by every scenario in the run.
+/// Launching Chromium is far slower than opening a browser context within it,
+/// so processes are launched as scenarios first need them and kept until the run ends.
+static POOL: OnceLock<Mutex<BrowserPool>> = OnceLock::new();
+
+#[derive(Debug, Default)]
+struct BrowserPool {
+    browsers: Vec<PooledBrowser>,
+    next: usize,
+}
+
+#[derive(Debug)]
+struct PooledBrowser {
+    browser: Arc<Browser>,
+    handler: JoinHandle<()>,
+}
+
+/// Hands out one of the pool's browsers, launching another while the pool isn't yet full
+pub(super) async fn acquire(options: &HumaneBrowserConfig) -> Arc<Browser> {
+    let mut pool = POOL.get_or_init(Default::default).lock().await;
+    let size = options.pool_size.unwrap_or(1).max(1);
+    if pool.browsers.len() < size {
+        let browser = launch(options).await;
+        pool.browsers.push(browser);
+    }
+
+    let index = pool.next % pool.browsers.len();
+    pool.next += 1;
+    Arc::clone(&pool.browsers[index].browser)
+}
+
+/// Closes every browser the run launched
+pub async fn close_all() {
+    let Some(pool) = POOL.get() else {
+        return;
+    };
+    let mut pool = pool.lock().await;
+    for pooled in pool.browsers.drain(..) {
+        // The process is killed as the browser drops if it didn't close cleanly
+        let _ = pooled.browser.execute(CloseParams::default()).await;
+        pooled.handler.abort();
+    }
+}
+
+async fn launch(options: &HumaneBrowserConfig) -> PooledBrowser {
+    let mut config = BrowserConfig::builder().args(&options.args);
+    if let Some(executable) = &options.executable {
+        config = config.chrome_executable(executable);
+    }
+    if options.headed {
+        config = config.with_head();
+    }
+    if options.no_sandbox {
+        config = config.no_sandbox();
+    }
+    if options.window_width.is_some() || options.window_height.is_some() {
+        let viewport = Viewport {
+            width: options.window_width.unwrap_or(800),
+            height: options.window_height.unwrap_or(600),
+            ..Default::default()
+        };
+        config = config
+            .window_size(viewport.width, viewport.height)
+            .viewport(viewport);
+    }
+    let config = config
+        .build()
+        .unwrap_or_else(|e| panic!("Browser could not be configured: {}", e));
+
+    let (browser, mut handler) = Browser::launch(config)
+        .await
+        .unwrap_or_else(|e| panic!("Browser could not be launched: {}", e));
+
+    PooledBrowser {
+        browser: Arc::new(browser),
+        handler: tokio::task::spawn(async move { while handler.next().await.is_some() {} }),
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Instant;
+
+use chromiumoxide::cdp::browser_protocol::network::{ClearBrowserCookiesParams, CookieParam};
+use chromiumoxide::cdp::browser_protocol::page::{
+    RemoveScriptToEvaluateOnNewDocumentParams, ScriptIdentifier,
+};
+use serde_json::json;
+use tokio::time::sleep;
+
+use super::{js_call, BrowserTester, WaitTimeout, POLL_INTERVAL};
+
+/// Writes storage items into the first document that loads, ignoring any frames within it.
+/// Scripts run on new documents can't be passed arguments, so the items are inlined as JSON.
+const SEED_STORAGE: &str = "(items) => {
+    if (window !== window.top) return;
+    for (const [storage, key, value] of items) window[storage].setItem(key, value);
+}";
+
+/// One of the page's web storage areas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebStorage {
+    Local,
+    Session,
+}
+
+impl WebStorage {
+    fn global(&self) -> &'static str {
+        match self {
+            WebStorage::Local => "localStorage",
+            WebStorage::Session => "sessionStorage",
+        }
+    }
+}
+
+impl FromStr for WebStorage {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(WebStorage::Local),
+            "session" => Ok(WebStorage::Session),
+            other => Err(format!("Unknown storage {:?}", other)),
+        }
+    }
+}
+
+impl fmt::Display for WebStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebStorage::Local => write!(f, "local storage"),
+            WebStorage::Session => write!(f, "session storage"),
+        }
+    }
+}
+
+/// Cookies and storage items set before any page was open,
+/// which are applied as the first page loads
+#[derive(Debug, Default)]
+pub(super) struct PendingState {
+    cookies: Vec<(String, String)>,
+    storage: Vec<(WebStorage, String, String)>,
+}
+
+impl BrowserTester {
+    pub async fn set_cookie(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.active_tab.is_none() {
+            self.pending.cookies.push((name.into(), value.into()));
+            return Ok(());
+        }
+        self.page()
+            .set_cookie(CookieParam::new(name, value))
+            .await?;
+        Ok(())
+    }
+
+    /// Waits for the active page's cookie to have the expected value, or to not exist when `None`
+    pub async fn expect_cookie(
+        &mut self,
+        name: &str,
+        expected: Option<&str>,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let timeout = self.timeout(step_timeout);
+        let start = Instant::now();
+        loop {
+            let value = self
+                .page()
+                .get_cookies()
+                .await?
+                .into_iter()
+                .find(|cookie| cookie.name == name)
+                .map(|cookie| cookie.value);
+            if value.as_deref() == expected {
+                return Ok(());
+            }
+
+            if start.elapsed() >= timeout {
+                return Err(format!(
+                    "Expected the cookie {:?} {}, but after {}ms it was: {:?}",
+                    name,
+                    expected
+                        .map(|v| format!("to be {:?}", v))
+                        .unwrap_or_else(|| "not to exist".into()),
+                    timeout.as_millis(),
+                    value
+                )
+                .into());
+            }
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    pub async fn clear_cookies(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.active_tab.is_none() {
+            self.pending.cookies.clear();
+            return Ok(());
+        }
+        self.page()
+            .execute(ClearBrowserCookiesParams::default())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_storage_item(
+        &mut self,
+        storage: WebStorage,
+        key: &str,
+        value: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.active_tab.is_none() {
+            self.pending
+                .storage
+                .push((storage, key.into(), value.into()));
+            return Ok(());
+        }
+        self.page()
+            .evaluate_function(js_call(
+                "(storage, key, value) => window[storage].setItem(key, value)",
+                [json!(storage.global()), json!(key), json!(value)],
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Waits for the active page's storage item to have the expected value, or to not exist when `None`
+    pub async fn expect_storage_item(
+        &mut self,
+        storage: WebStorage,
+        key: &str,
+        expected: Option<&str>,
+        step_timeout: Option<u64>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let js = js_call(
+            "(storage, key) => window[storage].getItem(key)",
+            [json!(storage.global()), json!(key)],
+        );
+        match self
+            .wait_for_value(js, step_timeout, |value: &Option<String>| {
+                value.as_deref() == expected
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(WaitTimeout {
+                waited,
+                last_value,
+                last_error,
+            }) => Err(format!(
+                "Expected the {} item {:?} {}, but after {}ms it was: {:?}{}",
+                storage,
+                key,
+                expected
+                    .map(|v| format!("to be {:?}", v))
+                    .unwrap_or_else(|| "not to exist".into()),
+                waited.as_millis(),
+                last_value.flatten(),
+                last_error
+                    .map(|e| format!("\nLast error: {}", e))
+                    .unwrap_or_default()
+            )
+            .into()),
+        }
+    }
+
+    pub async fn clear_storage(
+        &mut self,
+        storage: WebStorage,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.active_tab.is_none() {
+            self.pending.storage.retain(|(s, _, _)| *s != storage);
+            return Ok(());
+        }
+        self.page()
+            .evaluate_function(js_call(
+                "(storage) => window[storage].clear()",
+                [json!(storage.global())],
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Applies the pending state to the active page before it navigates to `url`,
+    /// returning the script to remove once it has loaded
+    pub(super) async fn seed_pending_state(
+        &mut self,
+        url: &str,
+    ) -> Result<Option<ScriptIdentifier>, Box<dyn std::error::Error>> {
+        let pending = std::mem::take(&mut self.pending);
+        for (name, value) in pending.cookies {
+            self.page()
+                .set_cookie(
+                    CookieParam::builder()
+                        .name(name)
+                        .value(value)
+                        .url(url)
+                        .build()?,
+                )
+                .await?;
+        }
+
+        if pending.storage.is_empty() {
+            return Ok(None);
+        }
+        let items: Vec<_> = pending
+            .storage
+            .iter()
+            .map(|(storage, key, value)| json!([storage.global(), key, value]))
+            .collect();
+        let script = self
+            .page()
+            .evaluate_on_new_document(format!("({})({})", SEED_STORAGE, json!(items)))
+            .await?;
+        Ok(Some(script))
+    }
+
+    /// Stops seeding storage once the first page has loaded,
+    /// so that reloads see whatever the page itself stored
+    pub(super) async fn finish_seeding(
+        &mut self,
+        script: Option<ScriptIdentifier>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(script) = script {
+            self.page()
+                .execute(RemoveScriptToEvaluateOnNewDocumentParams::new(script))
+                .await?;
+        }
+        Ok(())
+    }
+}
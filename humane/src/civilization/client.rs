@@ -0,0 +1,74 @@
+use std::fmt;
+
+use super::server::Certificate;
+
+/// The parts of a response that steps can assert on
+#[derive(Debug)]
+pub struct ClientResponse {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl ClientResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl fmt::Display for ClientResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {} → {}", self.method, self.url, self.status)?;
+        for (name, value) in &self.headers {
+            writeln!(f, "{}: {}", name, value)?;
+        }
+        write!(f, "\n{}", self.body)
+    }
+}
+
+/// Makes a request without following redirects, so that steps can assert on them.
+/// The run's self-signed certificate is trusted once anything has been served over HTTPS.
+pub async fn request(
+    method: &str,
+    url: &str,
+    headers: &[(String, String)],
+    body: Option<String>,
+) -> Result<ClientResponse, Box<dyn std::error::Error>> {
+    let mut client = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+    if let Some(certificate) = Certificate::generated() {
+        client = client.add_root_certificate(reqwest::Certificate::from_der(&certificate.der)?);
+    }
+    let client = client.build()?;
+    let mut request = client.request(reqwest::Method::from_bytes(method.as_bytes())?, url);
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+    if let Some(body) = body {
+        request = request.body(body);
+    }
+
+    let response = request.send().await?;
+    let status = response.status().as_u16();
+    let headers = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            )
+        })
+        .collect();
+    Ok(ClientResponse {
+        method: method.into(),
+        url: url.into(),
+        status,
+        headers,
+        body: response.text().await?,
+    })
+}
@@ -0,0 +1,174 @@
+use std::any::Any;
+use std::collections::HashSet;
+use std::panic::AssertUnwindSafe;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use cucumber::gherkin::{Feature, GherkinEnv, Step};
+use cucumber::step::{Collection, Context};
+use futures::future::LocalBoxFuture;
+use futures::FutureExt;
+use regex::Regex;
+use wax::Glob;
+
+use super::{Civilization, OPTIONS};
+use crate::parser::fill_step;
+
+/// Composite steps found in `.step` files below the test file root
+static COMPOSITES: OnceLock<Vec<CompositeStep>> = OnceLock::new();
+/// Every step, including composite steps, for composite steps to look their own steps up in
+static STEPS: OnceLock<Collection<Civilization>> = OnceLock::new();
+
+/// A reusable step that runs a sequence of other steps.
+/// Each scenario of a `.step` file defines one, with the scenario's name as the step's text,
+/// in which `<parameters>` match any text and fill the same placeholders in the steps it runs.
+#[derive(Debug)]
+struct CompositeStep {
+    name: String,
+    pattern: Regex,
+    parameters: Vec<String>,
+    steps: Vec<Step>,
+}
+
+impl CompositeStep {
+    fn new(name: &str, steps: Vec<Step>) -> Self {
+        let placeholder = Regex::new(r"<([^>\s]+)>").unwrap();
+        let mut pattern = String::from("^");
+        let mut parameters = vec![];
+        let mut literal_start = 0;
+        for captures in placeholder.captures_iter(name) {
+            let whole = captures.get(0).unwrap();
+            pattern.push_str(&regex::escape(&name[literal_start..whole.start()]));
+            pattern.push_str("(.*?)");
+            parameters.push(captures[1].to_string());
+            literal_start = whole.end();
+        }
+        pattern.push_str(&regex::escape(&name[literal_start..]));
+        pattern.push('$');
+
+        Self {
+            name: name.to_string(),
+            pattern: Regex::new(&pattern).unwrap(),
+            parameters,
+            steps,
+        }
+    }
+}
+
+fn composites() -> &'static [CompositeStep] {
+    COMPOSITES.get_or_init(|| {
+        load(
+            &OPTIONS
+                .get()
+                .expect("Humane options were configured")
+                .test_file_root,
+        )
+    })
+}
+
+fn load(root: &Path) -> Vec<CompositeStep> {
+    let glob = Glob::new("**/*.step").expect("Valid glob");
+    let mut files: Vec<_> = glob
+        .walk(root)
+        .flatten()
+        .map(|entry| entry.path().to_path_buf())
+        .collect();
+    files.sort();
+
+    let mut names = HashSet::new();
+    let mut composites = vec![];
+    for file in files {
+        let feature = Feature::parse_path(&file, GherkinEnv::default())
+            .unwrap_or_else(|e| panic!("Step file {:?} could not be parsed: {}", file, e));
+        for scenario in feature.scenarios {
+            if !names.insert(scenario.name.clone()) {
+                panic!(
+                    "The step {:?} in {:?} is defined more than once",
+                    scenario.name, file
+                );
+            }
+            composites.push(CompositeStep::new(&scenario.name, scenario.steps));
+        }
+    }
+    composites
+}
+
+/// Adds the composite steps defined for this run.
+/// Composite steps match any keyword, since the steps they run carry their own.
+pub fn register(mut collection: Collection<Civilization>) -> Collection<Civilization> {
+    for composite in composites() {
+        collection = collection
+            .given(None, composite.pattern.clone(), run)
+            .when(None, composite.pattern.clone(), run)
+            .then(None, composite.pattern.clone(), run);
+    }
+    collection
+}
+
+fn run(world: &mut Civilization, context: Context) -> LocalBoxFuture<'_, ()> {
+    Box::pin(async move {
+        let text = &context.step.value;
+        let composite = composites()
+            .iter()
+            .find(|composite| composite.pattern.is_match(text))
+            .expect("Composite steps only run for text they match");
+        let captures = composite.pattern.captures(text).unwrap();
+        let parameter = |name: &str| {
+            composite
+                .parameters
+                .iter()
+                .position(|parameter| parameter == name)
+                .map(|i| captures[i + 1].to_string())
+        };
+
+        // Steps can't branch, so a composite step that runs itself would never finish
+        if let Some(start) = world
+            .composite_stack
+            .iter()
+            .position(|name| name == &composite.name)
+        {
+            let cycle: Vec<_> = world.composite_stack[start..]
+                .iter()
+                .chain([&composite.name])
+                .map(|name| format!("`{}`", name))
+                .collect();
+            panic!(
+                "Composite steps run each other in a cycle: {}",
+                cycle.join(" → ")
+            );
+        }
+        world.composite_stack.push(composite.name.clone());
+
+        let steps = STEPS.get_or_init(super::steps);
+        for step in &composite.steps {
+            let mut step = step.clone();
+            fill_step(&mut step, &parameter);
+            let (step_fn, _, step_context) = steps
+                .find(&step)
+                .unwrap_or_else(|e| panic!("`{}{}` is ambiguous. {}", step.keyword, step.value, e))
+                .unwrap_or_else(|| {
+                    panic!("`{}{}` doesn't match any step", step.keyword, step.value)
+                });
+            if let Err(payload) = AssertUnwindSafe(step_fn(world, step_context))
+                .catch_unwind()
+                .await
+            {
+                panic!(
+                    "`{}{}` failed: {}",
+                    step.keyword,
+                    step.value,
+                    panic_message(&payload)
+                );
+            }
+        }
+        world.composite_stack.pop();
+    })
+}
+
+fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+        .unwrap_or_else(|| "(no message)".into())
+}
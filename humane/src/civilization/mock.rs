@@ -0,0 +1,164 @@
+use std::fmt;
+use std::sync::Mutex;
+
+use actix_web::dev::Server;
+use actix_web::http::StatusCode;
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use portpicker::pick_unused_port;
+
+/// A response the mock API gives to requests matching its method and path
+#[derive(Debug, Clone)]
+pub struct MockRoute {
+    pub method: String,
+    pub path: String,
+    pub status: u16,
+    pub body: String,
+}
+
+impl MockRoute {
+    fn matches(&self, method: &str, path: &str, query: &str) -> bool {
+        method_matches(&self.method, method) && path_matches(&self.path, path, query)
+    }
+}
+
+/// `*` matches any method
+fn method_matches(expected: &str, method: &str) -> bool {
+    expected == "*" || expected.eq_ignore_ascii_case(method)
+}
+
+/// Paths with a query string only match that query string, while paths without one match any
+fn path_matches(expected: &str, path: &str, query: &str) -> bool {
+    match expected.split_once('?') {
+        Some((expected_path, expected_query)) => expected_path == path && expected_query == query,
+        None => expected == path,
+    }
+}
+
+/// A request the mock API received, whether or not a route matched it
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl ReceivedRequest {
+    pub fn matches(&self, method: &str, path: &str) -> bool {
+        method_matches(method, &self.method) && path_matches(path, &self.path, &self.query)
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl fmt::Display for ReceivedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.query.as_str() {
+            "" => write!(f, "{} {}", self.method, self.path),
+            query => write!(f, "{} {}?{}", self.method, self.path, query),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MockState {
+    pub routes: Vec<MockRoute>,
+    pub received: Vec<ReceivedRequest>,
+}
+
+/// A mock HTTP API running for the length of a scenario
+#[derive(Debug)]
+pub struct MockApi {
+    pub port: u16,
+    pub state: web::Data<Mutex<MockState>>,
+}
+
+impl MockApi {
+    /// Binds the mock API to a free port, returning the server for the caller to run
+    pub fn start() -> (Self, Server) {
+        let state = web::Data::new(Mutex::new(MockState::default()));
+        for _ in 0..5 {
+            let port = pick_unused_port().expect("No port was available");
+            let app_state = state.clone();
+            if let Ok(bound) = HttpServer::new(move || {
+                App::new()
+                    .app_data(app_state.clone())
+                    .default_service(web::to(respond))
+            })
+            .bind(("127.0.0.1", port))
+            {
+                return (Self { port, state }, bound.run());
+            }
+        }
+        panic!("The mock API could not find a port to listen on");
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://localhost:{}", self.port)
+    }
+
+    pub fn add_route(&self, route: MockRoute) {
+        self.state.lock().unwrap().routes.push(route);
+    }
+
+    pub fn received(&self) -> Vec<ReceivedRequest> {
+        self.state.lock().unwrap().received.clone()
+    }
+}
+
+/// Answers with the most recently added route that matches, so later steps can override earlier ones
+async fn respond(
+    req: HttpRequest,
+    body: web::Bytes,
+    state: web::Data<Mutex<MockState>>,
+) -> HttpResponse {
+    let mut state = state.lock().unwrap();
+    let method = req.method().to_string();
+    let path = req.path().to_string();
+    let query = req.query_string().to_string();
+
+    let route = state
+        .routes
+        .iter()
+        .rev()
+        .find(|route| route.matches(&method, &path, &query))
+        .cloned();
+    state.received.push(ReceivedRequest {
+        method: method.clone(),
+        path: path.clone(),
+        query,
+        headers: req
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect(),
+        body: String::from_utf8_lossy(&body).into_owned(),
+    });
+
+    match route {
+        Some(route) => {
+            let content_type = if serde_json::from_str::<serde_json::Value>(&route.body).is_ok() {
+                "application/json"
+            } else {
+                "text/plain; charset=utf-8"
+            };
+            HttpResponse::build(
+                StatusCode::from_u16(route.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            )
+            .content_type(content_type)
+            .body(route.body)
+        }
+        None => HttpResponse::NotFound().body(format!("No mock route for {} {}", method, path)),
+    }
+}
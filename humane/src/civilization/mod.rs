@@ -1,11 +1,14 @@
 use actix_web::dev::ServerHandle;
-use cucumber::gherkin::Table;
+use cucumber::gherkin::{Scenario, Table};
 use portpicker::pick_unused_port;
-use std::collections::HashMap;
+use regex::{Captures, Regex};
+use std::collections::{HashMap, HashSet};
 use std::convert::Infallible;
 use std::io::{Read, Write};
 use std::process::Command;
 use std::str::from_utf8;
+use std::sync::OnceLock;
+use std::time::Duration;
 use std::{fs, path::PathBuf};
 use tempfile::tempdir;
 use tokio::task::JoinHandle;
@@ -13,10 +16,57 @@ use wax::Glob;
 
 use async_trait::async_trait;
 use browser::BrowserTester;
+use client::ClientResponse;
 use cucumber::{World, WorldInit};
+use mock::MockApi;
+use server::RequestLog;
+
+use crate::options::RobotHumaneConfig;
 
 mod browser;
+mod client;
+mod composite;
+mod mock;
+mod script;
+mod server;
 mod steps;
+mod visual;
+
+static OPTIONS: OnceLock<RobotHumaneConfig> = OnceLock::new();
+static SUBSTITUTION: OnceLock<Regex> = OnceLock::new();
+
+/// Milliseconds that steps wait for what they expect, unless configured with `browser.wait_timeout`
+const DEFAULT_WAIT_TIMEOUT: u64 = 2000;
+/// How often steps check for what they expect while waiting
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Cucumber creates each world without arguments,
+/// so the run's options are stored here for scenarios to read.
+pub fn configure(options: RobotHumaneConfig) {
+    let _ = OPTIONS.set(options);
+}
+
+/// Humane's steps, along with the script steps from the config and the composite steps defined in `.step` files
+pub fn steps() -> cucumber::step::Collection<Civilization> {
+    composite::register(script::register(Civilization::collection()))
+}
+
+/// Closes the browsers shared by the run's scenarios
+pub async fn close_browsers() {
+    browser::close_all().await;
+}
+
+/// Writes the JavaScript coverage collected from browser pages, when enabled
+pub fn write_coverage() {
+    if let Some(path) = &OPTIONS
+        .get()
+        .expect("Humane options were configured")
+        .browser
+        .coverage
+    {
+        browser::write_coverage(path);
+    }
+}
 
 #[derive(Debug)]
 struct CommandOutput {
@@ -30,13 +80,29 @@ pub struct Civilization {
     last_command_output: Option<CommandOutput>,
     browser: Option<BrowserTester>,
     assigned_server_port: Option<u16>,
+    named_server_ports: HashMap<String, u16>,
+    /// Servers served over HTTPS, by name, with the default server as an empty name
+    secure_servers: HashSet<String>,
+    mock_api: Option<MockApi>,
+    request_headers: Vec<(String, String)>,
+    last_response: Option<ClientResponse>,
+    served_requests: RequestLog,
     threads: Vec<JoinHandle<Result<(), std::io::Error>>>,
     handles: Vec<ServerHandle>,
     env_vars: HashMap<String, String>,
+    /// Values set by steps, for `{{name}}` substitutions
+    variables: HashMap<String, String>,
+    /// The values of a Scenario Outline's Examples row, by column
+    examples: HashMap<String, String>,
+    /// The names of the composite steps currently running, outermost first
+    composite_stack: Vec<String>,
 }
 
 impl Civilization {
     pub async fn shutdown(&mut self) {
+        if let Some(browser) = &mut self.browser {
+            browser.close().await;
+        }
         for handle in &self.handles {
             handle.stop(false).await;
         }
@@ -47,6 +113,31 @@ impl Civilization {
 }
 
 impl Civilization {
+    /// Reads the row an outline's scenario was expanded from, which the parser leaves as its only example
+    pub fn use_examples(&mut self, scenario: &Scenario) {
+        let rows = scenario
+            .examples
+            .first()
+            .and_then(|examples| examples.table.as_ref())
+            .map(|table| table.rows.as_slice())
+            .unwrap_or_default();
+        if let [header, row, ..] = rows {
+            self.examples = header.iter().cloned().zip(row.iter().cloned()).collect();
+        }
+    }
+
+    fn options(&self) -> &'static RobotHumaneConfig {
+        OPTIONS.get().expect("Humane options were configured")
+    }
+
+    /// Milliseconds that steps wait for what they expect when the step doesn't say
+    fn wait_timeout(&self) -> u64 {
+        self.options()
+            .browser
+            .wait_timeout
+            .unwrap_or(DEFAULT_WAIT_TIMEOUT)
+    }
+
     fn ensure_port(&mut self) -> u16 {
         if self.assigned_server_port.is_none() {
             self.assigned_server_port = pick_unused_port();
@@ -56,11 +147,73 @@ impl Civilization {
     fn purge_port(&mut self) {
         self.assigned_server_port = None;
     }
+    /// The port of a server named in a step, which stays the same for the whole scenario
+    fn ensure_named_port(&mut self, name: &str) -> u16 {
+        if !self.named_server_ports.contains_key(name) {
+            let port = pick_unused_port().expect("No port was available");
+            self.named_server_ports.insert(name.into(), port);
+        }
+        self.named_server_ports[name]
+    }
+    fn purge_named_port(&mut self, name: &str) {
+        self.named_server_ports.remove(name);
+    }
+    /// Resolves a step's URL, which is either a full URL or a path on the default or a named server
+    fn server_url(&mut self, url: &str, server: Option<&str>) -> String {
+        let url = self.process_substitutions(url);
+        if url.starts_with("http://") || url.starts_with("https://") {
+            return url;
+        }
+        let port = match server {
+            Some(name) => self.ensure_named_port(name),
+            None => self.ensure_port(),
+        };
+        let scheme = match self.secure_servers.contains(server.unwrap_or_default()) {
+            true => "https",
+            false => "http",
+        };
+        format!("{}://localhost:{}{}", scheme, port, url)
+    }
+    /// Marks a server as served over HTTPS, so its URLs use HTTPS and the browser accepts its certificate
+    async fn serve_securely(&mut self, name: &str) {
+        self.secure_servers.insert(name.into());
+        if let Some(browser) = &mut self.browser {
+            browser
+                .ignore_certificate_errors()
+                .await
+                .expect("The browser could be set to accept the server's certificate");
+        }
+    }
+    /// The scenario's mock API, started the first time a step or substitution needs it
+    fn ensure_mock_api(&mut self) -> &MockApi {
+        if self.mock_api.is_none() {
+            let (mock_api, server) = MockApi::start();
+            self.handles.push(server.handle());
+            self.threads.push(tokio::task::spawn(server));
+            self.mock_api = Some(mock_api);
+        }
+        self.mock_api.as_ref().unwrap()
+    }
     async fn ensure_browser(&mut self) -> &mut BrowserTester {
         if self.browser.is_none() {
-            self.browser = Some(BrowserTester::new().await);
+            let mut browser = BrowserTester::new(&self.options().browser).await;
+            if !self.secure_servers.is_empty() {
+                browser
+                    .ignore_certificate_errors()
+                    .await
+                    .expect("The browser could be set to accept the server's certificate");
+            }
+            self.browser = Some(browser);
         }
-        self.browser.as_mut().unwrap()
+        let server_names = self
+            .named_server_ports
+            .iter()
+            .map(|(name, port)| (*port, name.clone()))
+            .chain(self.assigned_server_port.map(|port| (port, String::new())))
+            .collect();
+        let browser = self.browser.as_mut().unwrap();
+        browser.name_servers(server_names);
+        browser
     }
 
     fn tmp_dir(&mut self) -> PathBuf {
@@ -75,6 +228,7 @@ impl Civilization {
     }
 
     fn tmp_file_path(&mut self, filename: &str) -> PathBuf {
+        let filename = self.process_substitutions(filename);
         let tmp_dir = self.tmp_dir();
         tmp_dir.join(PathBuf::from(filename))
     }
@@ -83,7 +237,7 @@ impl Civilization {
         let file_path = self.tmp_file_path(filename);
         fs::create_dir_all(file_path.parent().unwrap()).unwrap();
 
-        let contents = self.process_substitutions(contents);
+        let contents = self.process_file_substitutions(contents);
 
         let mut file = std::fs::File::create(&file_path).unwrap();
         if gzipped {
@@ -153,39 +307,99 @@ impl Civilization {
 
     fn set_env(&mut self, options: Option<&Table>) {
         if let Some(options) = options {
-            for row in &options.rows {
+            for row in &self.process_table(options).rows {
                 self.env_vars.insert(
-                    row.get(0).cloned().unwrap_or_default(),
+                    row.first().cloned().unwrap_or_default(),
                     row.get(1).cloned().unwrap_or_default(),
                 );
             }
         }
     }
 
+    /// Replaces every `{{...}}` that names a substitution, leaving any others as written
     fn process_substitutions<S: AsRef<str>>(&mut self, str: S) -> String {
-        let result = str
-            .as_ref()
-            .replace(
-                "{{humane_cwd}}",
-                std::env::current_dir()
-                    .unwrap()
-                    .to_str()
-                    .expect("Current dir should be utf-8"),
-            )
-            .replace(
-                "{{humane_temp_dir}}",
-                self.tmp_dir().to_str().expect("Invalid utf-8"),
-            );
+        self.substitute(str.as_ref(), true)
+    }
 
-        result
+    /// Like [`Self::process_substitutions`], but unset `env:` and unknown `example:` substitutions
+    /// are left as written rather than failing, since file contents may have their own `{{...}}` syntax
+    fn process_file_substitutions(&mut self, str: &str) -> String {
+        self.substitute(str, false)
     }
 
-    fn run_command(&mut self, options: Option<&Table>) {
-        let binary = std::env::var("TEST_BINARY").unwrap_or_else(|_| {
-            panic!("No binary supplied — please provide a TEST_BINARY environment variable");
-        });
+    fn substitute(&mut self, str: &str, strict: bool) -> String {
+        let pattern = SUBSTITUTION.get_or_init(|| Regex::new(r"\{\{([^{}]+)\}\}").unwrap());
+        pattern
+            .replace_all(str, |captures: &Captures| {
+                self.substitution(&captures[1], strict)
+                    .unwrap_or_else(|| captures[0].to_string())
+            })
+            .into_owned()
+    }
 
-        let cli = build_command(&binary, None, options);
+    fn process_table(&mut self, table: &Table) -> Table {
+        let mut table = table.clone();
+        for cell in table.rows.iter_mut().flatten() {
+            *cell = self.process_substitutions(cell.as_str());
+        }
+        table
+    }
+
+    fn substitution(&mut self, name: &str, strict: bool) -> Option<String> {
+        let value = match name {
+            "humane_cwd" => std::env::current_dir()
+                .unwrap()
+                .to_str()
+                .expect("Current dir should be utf-8")
+                .to_string(),
+            "humane_temp_dir" => self.tmp_dir().to_str().expect("Invalid utf-8").to_string(),
+            "humane_binary" => binary_path(&test_binary()),
+            "humane_mock_url" => self.ensure_mock_api().url(),
+            // Ports are assigned as soon as they're referenced, so commands can know a server's port before it's served
+            "humane_port" => self.ensure_port().to_string(),
+            _ => match name.split_once(':') {
+                Some(("humane_port", server)) => self.ensure_named_port(server).to_string(),
+                Some(("env", var)) => {
+                    let value = self
+                        .env_vars
+                        .get(var)
+                        .cloned()
+                        .or_else(|| std::env::var(var).ok());
+                    if value.is_none() && strict {
+                        panic!(
+                            "{{{{env:{}}}}} refers to an unset environment variable",
+                            var
+                        );
+                    }
+                    return value;
+                }
+                Some(("example", column)) => {
+                    let value = self.examples.get(column).cloned();
+                    if value.is_none() && strict {
+                        panic!(
+                            "{{{{example:{}}}}} refers to an Examples column that doesn't exist. Columns: {:?}",
+                            column,
+                            self.examples.keys().collect::<Vec<_>>()
+                        );
+                    }
+                    return value;
+                }
+                // Anything else is left as written unless it names a variable,
+                // so that templates in test files keep their own `{{...}}` syntax
+                _ => {
+                    return self
+                        .variables
+                        .get(name)
+                        .or_else(|| self.options().variables.get(name))
+                        .cloned()
+                }
+            },
+        };
+        Some(value)
+    }
+
+    fn run_command(&mut self, options: Option<&Table>) {
+        let cli = build_command(&test_binary(), None, options);
         self.run_custom(cli);
     }
 
@@ -196,7 +410,7 @@ impl Civilization {
         command
             .arg("-c")
             .current_dir(self.tmp_dir())
-            .arg(&processed_cmd.replace(std::path::MAIN_SEPARATOR, "/"));
+            .arg(processed_cmd.replace(std::path::MAIN_SEPARATOR, "/"));
 
         for (key, val) in &self.env_vars {
             command.env(key, val);
@@ -233,14 +447,26 @@ impl BinaryCommand {
     }
 }
 
-fn build_command(binary: &str, subcommand: Option<&str>, options: Option<&Table>) -> String {
+fn test_binary() -> String {
+    std::env::var("TEST_BINARY").unwrap_or_else(|_| {
+        panic!("No binary supplied — please provide a TEST_BINARY environment variable");
+    })
+}
+
+fn binary_path(binary: &str) -> String {
     let cwd = std::env::current_dir().unwrap();
-    let binary_path = cwd.join(PathBuf::from(binary));
-    let binary_path = binary_path.to_str().unwrap();
+    cwd.join(PathBuf::from(binary))
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+fn build_command(binary: &str, subcommand: Option<&str>, options: Option<&Table>) -> String {
+    let binary_path = binary_path(binary);
 
     let mut command = match subcommand {
         Some(subcommand) => BinaryCommand(format!("{} {}", binary_path, subcommand)),
-        None => BinaryCommand(binary_path.into()),
+        None => BinaryCommand(binary_path),
     };
 
     if let Some(options) = options {
@@ -0,0 +1,121 @@
+use std::process::Command;
+use std::str::from_utf8;
+use std::sync::OnceLock;
+
+use cucumber::step::{Collection, Context};
+use futures::future::LocalBoxFuture;
+use regex::Regex;
+
+use super::steps::step_definitions::StepDebug;
+use super::{binary_path, Civilization, CommandOutput, OPTIONS};
+
+/// Script steps from the config, with their patterns compiled
+static SCRIPTS: OnceLock<Vec<ScriptStep>> = OnceLock::new();
+
+/// A step that runs an executable with the captures of its pattern as arguments
+#[derive(Debug)]
+struct ScriptStep {
+    pattern: Regex,
+    program: String,
+}
+
+fn scripts() -> &'static [ScriptStep] {
+    SCRIPTS.get_or_init(|| {
+        OPTIONS
+            .get()
+            .expect("Humane options were configured")
+            .steps
+            .iter()
+            .map(|step| {
+                let pattern = format!("^(DEBUG )?(?:{})$", step.pattern);
+                ScriptStep {
+                    pattern: Regex::new(&pattern).unwrap_or_else(|e| {
+                        panic!("Invalid pattern for the step {:?}: {}", step.pattern, e)
+                    }),
+                    // Paths run from the temp dir, so relative paths are resolved against where Humane is run,
+                    // while bare names are left for the PATH to find
+                    program: if step.run.contains('/') || step.run.contains('\\') {
+                        binary_path(&step.run)
+                    } else {
+                        step.run.clone()
+                    },
+                }
+            })
+            .collect()
+    })
+}
+
+/// Adds the script steps from the config, which match any keyword and, like Humane's own steps, a `DEBUG ` prefix
+pub fn register(mut collection: Collection<Civilization>) -> Collection<Civilization> {
+    for script in scripts() {
+        collection = collection
+            .given(None, script.pattern.clone(), run)
+            .when(None, script.pattern.clone(), run)
+            .then(None, script.pattern.clone(), run);
+    }
+    collection
+}
+
+fn run(world: &mut Civilization, context: Context) -> LocalBoxFuture<'_, ()> {
+    Box::pin(async move {
+        let text = &context.step.value;
+        let script = scripts()
+            .iter()
+            .find(|script| script.pattern.is_match(text))
+            .expect("Script steps only run for text they match");
+        let captures = script.pattern.captures(text).unwrap();
+        let debug: StepDebug = captures
+            .get(1)
+            .map_or("", |debug| debug.as_str())
+            .parse()
+            .unwrap();
+        let arguments: Vec<String> = captures
+            .iter()
+            .skip(2)
+            .map(|capture| {
+                world.process_substitutions(capture.map(|c| c.as_str()).unwrap_or_default())
+            })
+            .collect();
+
+        // Run through `sh` as commands are, so scripts don't need to be directly executable on every platform
+        let mut command = Command::new("sh");
+        command
+            .arg("-c")
+            .arg(shell_command(&script.program, &arguments))
+            .current_dir(world.tmp_dir());
+        for (key, val) in &world.env_vars {
+            command.env(key, val);
+        }
+
+        let output = command
+            .output()
+            .unwrap_or_else(|e| panic!("Failed to run {}: {}", script.program, e));
+        let stdout = from_utf8(&output.stdout).unwrap_or("failed utf8");
+        let stderr = from_utf8(&output.stderr).unwrap_or("failed utf8");
+        let report = format!(
+            "STDOUT:\n-----\n{}\n-----\nSTDERR:\n-----\n{}\n-----\n",
+            stdout, stderr
+        );
+        if !output.status.success() {
+            panic!(
+                "{} exited with {}\n{}",
+                script.program, output.status, report
+            );
+        }
+        debug.log(&report);
+
+        world.last_command_output = Some(CommandOutput {
+            stdout: stdout.into(),
+            stderr: stderr.into(),
+        });
+    })
+}
+
+/// Quotes the program and each argument for `sh`
+fn shell_command(program: &str, arguments: &[String]) -> String {
+    let quote = |value: &str| format!("'{}'", value.replace('\'', "'\\''"));
+    std::iter::once(quote(&program.replace(std::path::MAIN_SEPARATOR, "/")))
+        .chain(arguments.iter().map(|argument| quote(argument)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use actix_files::NamedFile;
+use actix_web::http::header::{self, ContentEncoding, HeaderName, HeaderValue};
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpRequest, HttpResponse};
+use rustls::pki_types::{CertificateDer, PrivatePkcs8KeyDer};
+use tokio::time::{sleep, Instant};
+
+use super::client;
+
+/// Pre-compressed siblings of a file that can be served in its place, e.g. `index.html.br`
+const ENCODINGS: [(&str, &str, ContentEncoding); 2] = [
+    ("br", "br", ContentEncoding::Brotli),
+    ("gzip", "gz", ContentEncoding::Gzip),
+];
+
+/// How long a freshly started server has to start accepting connections
+const READY_TIMEOUT: Duration = Duration::from_secs(10);
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(5);
+/// Marks the requests made while waiting for a server, which aren't recorded as served requests
+pub const READY_PROBE_HEADER: &str = "x-humane-ready-probe";
+
+/// The self-signed certificate for serving over HTTPS, generated the first time it's needed each run
+static CERTIFICATE: OnceLock<Certificate> = OnceLock::new();
+
+/// A certificate valid for `localhost` and `127.0.0.1`
+#[derive(Debug)]
+pub struct Certificate {
+    pub der: CertificateDer<'static>,
+    key: PrivatePkcs8KeyDer<'static>,
+}
+
+impl Certificate {
+    pub fn get() -> &'static Certificate {
+        CERTIFICATE.get_or_init(|| {
+            let generated =
+                rcgen::generate_simple_self_signed(vec!["localhost".into(), "127.0.0.1".into()])
+                    .expect("A self-signed certificate could be generated");
+            Certificate {
+                der: generated.cert.der().clone(),
+                key: PrivatePkcs8KeyDer::from(generated.key_pair.serialize_der()),
+            }
+        })
+    }
+
+    /// The certificate, if anything has been served over HTTPS this run
+    pub fn generated() -> Option<&'static Certificate> {
+        CERTIFICATE.get()
+    }
+
+    pub fn server_config(&self) -> rustls::ServerConfig {
+        rustls::ServerConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .expect("The default TLS versions are supported")
+        .with_no_client_auth()
+        .with_single_cert(vec![self.der.clone()], self.key.clone_key().into())
+        .expect("The generated certificate is valid")
+    }
+}
+
+/// Every request handled by the scenario's served directories, in the order they were handled
+pub type RequestLog = Arc<Mutex<Vec<ServedRequest>>>;
+
+/// A request handled by a served directory
+#[derive(Debug, Clone)]
+pub struct ServedRequest {
+    /// The name the directory was served as, empty for the default server
+    pub server: String,
+    pub method: String,
+    /// The requested path, including its query string
+    pub path: String,
+    pub status: u16,
+    /// The size of the response body
+    pub bytes: u64,
+}
+
+impl fmt::Display for ServedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.server.is_empty() {
+            write!(f, "[{}] ", self.server)?;
+        }
+        write!(
+            f,
+            "{} {} → {} ({} bytes)",
+            self.method, self.path, self.status, self.bytes
+        )
+    }
+}
+
+/// A directory served by the static server, and how it should be served
+#[derive(Debug, Clone)]
+pub struct StaticSite {
+    pub root: PathBuf,
+    /// Headers added to every response
+    pub headers: Vec<(HeaderName, HeaderValue)>,
+    /// Content types by file extension, overriding the guessed types
+    pub mime_types: HashMap<String, mime_guess::Mime>,
+    /// Content encodings (`br` / `gzip`) to look for pre-compressed files with, in order of preference
+    pub precompressed: Vec<String>,
+    /// The URL path the site is mounted at, always starting and ending with a `/`
+    pub base_path: String,
+    /// File served for any path within the site that has no file, as single page apps expect
+    pub fallback: Option<PathBuf>,
+    /// File served with a 404 status for any path within the site that has no file
+    pub not_found: Option<PathBuf>,
+    /// Whether the site is served over HTTPS, with the run's self-signed certificate
+    pub https: bool,
+}
+
+impl StaticSite {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            headers: vec![],
+            mime_types: HashMap::new(),
+            precompressed: vec![],
+            base_path: "/".into(),
+            fallback: None,
+            not_found: None,
+            https: false,
+        }
+    }
+
+    /// Applies a row of the serve step's table, such as `| header:Cache-Control | max-age=60 |`
+    pub fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        if let Some(name) = key.strip_prefix("header:") {
+            let name = HeaderName::try_from(name.trim())
+                .map_err(|e| format!("Invalid header name {:?}: {}", name, e))?;
+            let value = HeaderValue::try_from(value)
+                .map_err(|e| format!("Invalid value for the {} header: {}", name, e))?;
+            self.headers.push((name, value));
+        } else if let Some(extension) = key.strip_prefix("mime:") {
+            let mime = value
+                .parse()
+                .map_err(|e| format!("Invalid MIME type {:?}: {}", value, e))?;
+            self.mime_types
+                .insert(extension.trim().trim_start_matches('.').into(), mime);
+        } else if key == "precompressed" {
+            for encoding in value.split(',').map(str::trim) {
+                if !ENCODINGS.iter().any(|(name, _, _)| *name == encoding) {
+                    return Err(format!(
+                        "Unsupported pre-compressed encoding {:?}, expected br or gzip",
+                        encoding
+                    ));
+                }
+                self.precompressed.push(encoding.into());
+            }
+        } else if key == "base path" {
+            let path = value.trim().trim_matches('/');
+            self.base_path = match path {
+                "" => "/".into(),
+                path => format!("/{}/", path),
+            };
+        } else if key == "fallback" {
+            self.fallback = Some(self.site_file(value)?);
+        } else if key == "not found" {
+            self.not_found = Some(self.site_file(value)?);
+        } else if key == "https" {
+            self.https = match value.trim() {
+                "true" => true,
+                "false" => false,
+                value => return Err(format!("Expected true or false for https, not {:?}", value)),
+            };
+        } else {
+            return Err(format!(
+                "Unknown server option {:?}, expected header:<name>, mime:<extension>, precompressed, base path, fallback, not found, or https",
+                key
+            ));
+        }
+        Ok(())
+    }
+
+    /// A file within the site's directory, for the options that name one
+    fn site_file(&self, file: &str) -> Result<PathBuf, String> {
+        let file = self.root.join(file.trim().trim_start_matches('/'));
+        if !file.is_file() {
+            return Err(format!("{:?} is not a file", file));
+        }
+        Ok(file)
+    }
+
+    /// The file on disk for a path within the site, refusing paths that escape the site's root
+    /// and, as actix-files does, hidden files and directories such as `.git` or `.env`
+    fn resolve(&self, path: &str) -> Option<PathBuf> {
+        let relative = Path::new(path.trim_start_matches('/'));
+        if relative.components().any(|c| match c {
+            Component::Normal(segment) => segment.to_string_lossy().starts_with('.'),
+            _ => true,
+        }) {
+            return None;
+        }
+
+        let file = self.root.join(relative);
+        if file.is_dir() {
+            return Some(file.join("index.html")).filter(|index| index.is_file());
+        }
+        Some(file).filter(|file| file.is_file())
+    }
+
+    /// The best pre-compressed sibling of a file that the client accepts
+    fn compressed_sibling(
+        &self,
+        file: &Path,
+        req: &HttpRequest,
+    ) -> Option<(PathBuf, ContentEncoding)> {
+        let accepted = req
+            .headers()
+            .get(header::ACCEPT_ENCODING)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        let accepts = |encoding: &str| {
+            accepted.split(',').any(|part| {
+                let mut params = part.split(';').map(str::trim);
+                params.next() == Some(encoding) && !params.any(|p| p == "q=0")
+            })
+        };
+
+        self.precompressed.iter().find_map(|encoding| {
+            let (name, extension, content_encoding) =
+                ENCODINGS.iter().find(|(name, _, _)| name == encoding)?;
+            let mut sibling = file.as_os_str().to_owned();
+            sibling.push(format!(".{}", extension));
+            let sibling = PathBuf::from(sibling);
+            (accepts(name) && sibling.is_file()).then_some((sibling, *content_encoding))
+        })
+    }
+
+    fn content_type(&self, file: &Path) -> mime_guess::Mime {
+        file.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| self.mime_types.get(ext).cloned())
+            .unwrap_or_else(|| mime_guess::from_path(file).first_or_octet_stream())
+    }
+}
+
+/// Waits until a server on the port responds to a request, returning how long that took.
+/// A bound socket queues connections before the server's workers run, so connecting alone proves nothing.
+pub async fn wait_until_ready(port: u16, https: bool) -> Result<Duration, String> {
+    let url = format!(
+        "{}://localhost:{}/",
+        if https { "https" } else { "http" },
+        port
+    );
+    let headers = [(READY_PROBE_HEADER.to_string(), "true".to_string())];
+    let start = Instant::now();
+    loop {
+        let error = match client::request("HEAD", &url, &headers, None).await {
+            Ok(_) => return Ok(start.elapsed()),
+            Err(e) => e.to_string(),
+        };
+        if start.elapsed() >= READY_TIMEOUT {
+            return Err(format!(
+                "The server on port {} did not respond within {}ms: {}",
+                port,
+                READY_TIMEOUT.as_millis(),
+                error
+            ));
+        }
+        sleep(READY_POLL_INTERVAL).await;
+    }
+}
+
+/// Serves a file from the site, as `index.html` for directories
+pub async fn serve_file(req: HttpRequest, site: web::Data<StaticSite>) -> HttpResponse {
+    let path = format!("/{}", req.match_info().query("path"));
+
+    let mut response = if format!("{}/", path) == site.base_path {
+        // Relative URLs only resolve within the site when its root has a trailing slash
+        HttpResponse::MovedPermanently()
+            .insert_header((header::LOCATION, site.base_path.clone()))
+            .finish()
+    } else {
+        let within_site = path.strip_prefix(&site.base_path);
+        let found = within_site.and_then(|path| site.resolve(path));
+        match (found, within_site) {
+            (Some(file), _) => send_file(&site, &file, &req, StatusCode::OK).await,
+            (None, Some(_)) if site.fallback.is_some() => {
+                send_file(&site, site.fallback.as_ref().unwrap(), &req, StatusCode::OK).await
+            }
+            (None, Some(_)) if site.not_found.is_some() => {
+                let not_found = site.not_found.as_ref().unwrap();
+                send_file(&site, not_found, &req, StatusCode::NOT_FOUND).await
+            }
+            _ => HttpResponse::NotFound().body("Not Found"),
+        }
+    };
+
+    for (name, value) in &site.headers {
+        response.headers_mut().insert(name.clone(), value.clone());
+    }
+    response
+}
+
+/// Sends the file with the given status. Only a 200 honours conditional and Range requests,
+/// so that a page served for a missing path is always sent in full as that status.
+async fn send_file(
+    site: &StaticSite,
+    file: &Path,
+    req: &HttpRequest,
+    status: StatusCode,
+) -> HttpResponse {
+    let content_type = site.content_type(file);
+    let (path, encoding) = match site.compressed_sibling(file, req) {
+        Some((sibling, encoding)) => (sibling, Some(encoding)),
+        None => (file.to_path_buf(), None),
+    };
+
+    let mut response = if status == StatusCode::OK {
+        match NamedFile::open_async(&path).await {
+            Ok(named) => {
+                let named = match encoding {
+                    Some(encoding) => named.set_content_encoding(encoding),
+                    None => named,
+                };
+                named
+                    .set_content_type(content_type)
+                    .disable_content_disposition()
+                    .into_response(req)
+            }
+            Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+        }
+    } else {
+        // NamedFile would answer a Range request with part of the page, so other statuses send it whole
+        match tokio::fs::read(&path).await {
+            Ok(contents) => {
+                let mut response = HttpResponse::build(status);
+                response.content_type(content_type);
+                if let Some(encoding) = encoding {
+                    response.insert_header(encoding);
+                }
+                response.body(contents)
+            }
+            Err(e) => return HttpResponse::InternalServerError().body(e.to_string()),
+        }
+    };
+
+    if !site.precompressed.is_empty() {
+        response
+            .headers_mut()
+            .insert(header::VARY, HeaderValue::from_static("Accept-Encoding"));
+    }
+    response
+}
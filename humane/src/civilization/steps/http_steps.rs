@@ -0,0 +1,481 @@
+use crate::civilization::browser::UrlPattern;
+use crate::civilization::client::{self, ClientResponse};
+use crate::civilization::mock::{MockRoute, ReceivedRequest};
+use crate::civilization::server::ServedRequest;
+use crate::civilization::steps::step_definitions::{assert_json_values, normalize_table_cell, Not};
+use crate::civilization::{Civilization, POLL_INTERVAL};
+use cucumber::gherkin::Step;
+use cucumber::{given, then, when};
+use serde_json::Value;
+use tokio::time::{sleep, Duration, Instant};
+
+#[given(regex = "^the mock API has the routes:$")]
+fn mock_routes(world: &mut Civilization, step: &Step) {
+    let rows = &step
+        .table
+        .as_ref()
+        .expect("This step requires a table")
+        .rows;
+    for row in rows {
+        let cell = |i: usize| normalize_table_cell(row.get(i).map(String::as_str).unwrap_or(""));
+        let route = MockRoute {
+            method: cell(0).to_uppercase(),
+            path: world.process_substitutions(cell(1)),
+            status: cell(2)
+                .parse()
+                .unwrap_or_else(|_| panic!("{:?} is not a status code", cell(2))),
+            body: world.process_substitutions(cell(3)),
+        };
+        world.ensure_mock_api().add_route(route);
+    }
+}
+
+#[given(
+    regex = "^the mock API responds to (\\w+|\\*) (?:\"|')([^\"']*)(?:\"|')(?: with the status (\\d+))?$"
+)]
+fn mock_route(world: &mut Civilization, method: String, path: String, status: String) {
+    add_mock_route(world, method, path, status, String::new());
+}
+
+#[given(
+    regex = "^the mock API responds to (\\w+|\\*) (?:\"|')([^\"']*)(?:\"|')(?: with the status (\\d+))? with:$"
+)]
+fn mock_route_with_body(
+    world: &mut Civilization,
+    step: &Step,
+    method: String,
+    path: String,
+    status: String,
+) {
+    let body = step
+        .docstring
+        .as_ref()
+        .expect("This step requires a docstring");
+    let body = world.process_substitutions(body.trim());
+    add_mock_route(world, method, path, status, body);
+}
+
+#[then(
+    regex = "^the mock API should (not )?have received (\\w+|\\*) (?:\"|')([^\"']*)(?:\"|')(?: (\\d+) times?)?$"
+)]
+fn mock_received(world: &mut Civilization, not: Not, method: String, path: String, times: String) {
+    let path = world.process_substitutions(path);
+    let received = world.ensure_mock_api().received();
+    let count = received
+        .iter()
+        .filter(|request| request.matches(&method, &path))
+        .count();
+
+    let (met, expected) = match times.as_str() {
+        "" if not.0 => (count == 0, "at all".to_string()),
+        "" => (count > 0, "at least once".to_string()),
+        times => {
+            let times: usize = times.parse().expect("Count was a number");
+            ((count == times) != not.0, format!("{} time(s)", times))
+        }
+    };
+    if !met {
+        panic!(
+            "Expected the mock API to {}receive {} {} {}, but it was received {} time(s). All requests:\n{}",
+            if not.0 { "not " } else { "" },
+            method,
+            path,
+            expected,
+            count,
+            list_received(&received)
+        );
+    }
+}
+
+#[then(
+    regex = "^the mock API should have received (\\w+|\\*) (?:\"|')([^\"']*)(?:\"|') with the headers:$"
+)]
+fn mock_received_headers(world: &mut Civilization, step: &Step, method: String, path: String) {
+    let request = last_received(world, &method, &path);
+    let rows = &step
+        .table
+        .as_ref()
+        .expect("This step requires a table")
+        .rows;
+    for row in rows {
+        let name = normalize_table_cell(&row[0]);
+        let expected = world.process_substitutions(normalize_table_cell(&row[1]));
+        match request.header(&name) {
+            Some(value) => assert_eq!(
+                value, expected,
+                "The {} header of {} was {:?}, not {:?}",
+                name, request, value, expected
+            ),
+            None => panic!(
+                "{} had no {} header. Headers:\n{}",
+                request,
+                name,
+                request
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+        }
+    }
+}
+
+#[then(
+    regex = "^the mock API should have received (\\w+|\\*) (?:\"|')([^\"']*)(?:\"|') with the JSON values:$"
+)]
+fn mock_received_json_values(world: &mut Civilization, step: &Step, method: String, path: String) {
+    let request = last_received(world, &method, &path);
+    let table = world.process_table(step.table.as_ref().expect("This step requires a table"));
+    assert_json_values(&parse_json_body(&request), &table);
+}
+
+#[then(
+    regex = "^the mock API should have received (\\w+|\\*) (?:\"|')([^\"']*)(?:\"|') with the JSON body:$"
+)]
+fn mock_received_json_body(world: &mut Civilization, step: &Step, method: String, path: String) {
+    let request = last_received(world, &method, &path);
+    let expected = step
+        .docstring
+        .as_ref()
+        .expect("This step requires a docstring");
+    let expected: Value = serde_json::from_str(&world.process_substitutions(expected))
+        .expect("The expected body is valid JSON");
+    assert_eq!(
+        parse_json_body(&request),
+        expected,
+        "{} had a different JSON body",
+        request
+    );
+}
+
+#[given(regex = "^the request header (?:\"|')([^\"']*)(?:\"|') is (?:\"|')(.*)(?:\"|')$")]
+fn request_header(world: &mut Civilization, name: String, value: String) {
+    let value = world.process_substitutions(value);
+    world.request_headers.push((name, value));
+}
+
+#[when(regex = "^I request ([A-Z]+) (?:\"|')(.*?)(?:\"|')(?: from (?:\"|')([^\"']*)(?:\"|'))?$")]
+async fn request(world: &mut Civilization, method: String, path: String, server: String) {
+    send_request(world, &method, &path, &server, vec![], None).await;
+}
+
+#[when(
+    regex = "^I request ([A-Z]+) (?:\"|')(.*?)(?:\"|')(?: from (?:\"|')([^\"']*)(?:\"|'))? with the headers:$"
+)]
+async fn request_with_headers(
+    world: &mut Civilization,
+    step: &Step,
+    method: String,
+    path: String,
+    server: String,
+) {
+    let rows = &step
+        .table
+        .as_ref()
+        .expect("This step requires a table")
+        .rows;
+    let headers = rows
+        .iter()
+        .map(|row| {
+            (
+                normalize_table_cell(&row[0]),
+                world.process_substitutions(normalize_table_cell(&row[1])),
+            )
+        })
+        .collect();
+    send_request(world, &method, &path, &server, headers, None).await;
+}
+
+#[when(
+    regex = "^I request ([A-Z]+) (?:\"|')(.*?)(?:\"|')(?: from (?:\"|')([^\"']*)(?:\"|'))? with the body:$"
+)]
+async fn request_with_body(
+    world: &mut Civilization,
+    step: &Step,
+    method: String,
+    path: String,
+    server: String,
+) {
+    let body = step
+        .docstring
+        .as_ref()
+        .expect("This step requires a docstring");
+    let body = world.process_substitutions(body.trim());
+    send_request(world, &method, &path, &server, vec![], Some(body)).await;
+}
+
+#[then(regex = "^the response status should be (\\d+)$")]
+fn response_status(world: &mut Civilization, status: u16) {
+    let response = last_response(world);
+    assert_eq!(
+        response.status, status,
+        "Expected a {} status, but the response was:\n{}",
+        status, response
+    );
+}
+
+#[then(
+    regex = "^the response header (?:\"|')([^\"']*)(?:\"|') should (be|contain) (?:\"|')(.*)(?:\"|')$"
+)]
+fn response_header(world: &mut Civilization, name: String, comparison: String, expected: String) {
+    let expected = world.process_substitutions(expected);
+    let response = last_response(world);
+    let value = response
+        .header(&name)
+        .unwrap_or_else(|| panic!("The response had no {} header:\n{}", name, response));
+    let matches = match comparison.as_str() {
+        "be" => value == expected,
+        _ => value.contains(&expected),
+    };
+    assert!(
+        matches,
+        "Expected the {} header to {} {:?}, but it was {:?}",
+        name, comparison, expected, value
+    );
+}
+
+#[then(regex = "^the response header (?:\"|')([^\"']*)(?:\"|') should not exist$")]
+fn response_header_missing(world: &mut Civilization, name: String) {
+    let response = last_response(world);
+    if let Some(value) = response.header(&name) {
+        panic!(
+            "Expected the response to have no {} header, but it was {:?}",
+            name, value
+        );
+    }
+}
+
+#[then(regex = "^the response body should (not )?contain (?:\"|')(.*)(?:\"|')$")]
+fn response_body_contains(world: &mut Civilization, not: Not, expected: String) {
+    let expected = world.process_substitutions(expected);
+    let response = last_response(world);
+    assert!(
+        response.body.contains(&expected) != not.0,
+        "Expected the response body to {}contain {:?}, but the response was:\n{}",
+        if not.0 { "not " } else { "" },
+        expected,
+        response
+    );
+}
+
+#[then(regex = "^the response body should be:$")]
+fn response_body_is(world: &mut Civilization, step: &Step) {
+    let expected = step
+        .docstring
+        .as_ref()
+        .expect("This step requires a docstring");
+    let expected = world.process_substitutions(expected.trim());
+    let response = last_response(world);
+    assert_eq!(response.body.trim(), expected);
+}
+
+#[then(regex = "^the response should contain the JSON values:$")]
+fn response_json_values(world: &mut Civilization, step: &Step) {
+    let table = world.process_table(step.table.as_ref().expect("This step requires a table"));
+    let response = last_response(world);
+    let parsed_json: Value = serde_json::from_str(&response.body).unwrap_or_else(|e| {
+        panic!(
+            "The response body was not valid JSON: {}\nThe response was:\n{}",
+            e, response
+        )
+    });
+    assert_json_values(&parsed_json, &table);
+}
+
+/// Positive assertions wait for the request, as browser steps wait for the page,
+/// up to `browser.wait_timeout` (2000ms by default) or the step's `within`.
+/// Negative assertions check the log as it is, or watch it for the whole `within` duration when given one.
+#[then(
+    regex = "^the server(?: (?:\"|')([^\"']*)(?:\"|'))? should (not )?have received a request for (?:\"|')([^\"']*)(?:\"|')(?: (\\d+) times?)?(?: with the method (\\w+))?(?: with the status (\\d+))?(?: within (\\d+)ms)?$"
+)]
+async fn server_received(world: &mut Civilization, captures: &[String]) {
+    let expected = ExpectedRequest::new(world, captures);
+    let timeout = match (expected.within, expected.not) {
+        (Some(within), _) => within,
+        (None, true) => 0,
+        (None, false) => world.wait_timeout(),
+    };
+    let deadline = Instant::now() + Duration::from_millis(timeout);
+    let (count, met) = loop {
+        let count = world
+            .served_requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| expected.matches(request))
+            .count();
+        let received = match expected.times {
+            Some(times) => count == times,
+            None => count > 0,
+        };
+        let timed_out = Instant::now() >= deadline;
+        if expected.not {
+            // A request that shouldn't arrive fails as soon as it does, and passes once the time is up
+            if received {
+                break (count, false);
+            }
+            if timed_out {
+                break (count, true);
+            }
+        } else {
+            // A request that should arrive passes as soon as it does, and fails once the time is up
+            if received {
+                break (count, true);
+            }
+            if timed_out {
+                break (count, false);
+            }
+        }
+        sleep(POLL_INTERVAL).await;
+    };
+
+    if !met {
+        let expectation = match expected.times {
+            Some(times) => format!("{} time(s)", times),
+            None if expected.not => "at all".into(),
+            None => "at least once".into(),
+        };
+        let log = world.served_requests.lock().unwrap();
+        panic!(
+            "Expected the server to {}receive a request for {} {}, but it was received {} time(s). All requests:\n{}",
+            if expected.not { "not " } else { "" },
+            expected.pattern,
+            expectation,
+            count,
+            match log.is_empty() {
+                true => "(none)".into(),
+                false => log
+                    .iter()
+                    .map(|request| format!("  {}", request))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            }
+        );
+    }
+}
+
+/// The request a `the server should have received` step expects, from its captures
+struct ExpectedRequest {
+    server: String,
+    not: bool,
+    pattern: UrlPattern,
+    times: Option<usize>,
+    method: Option<String>,
+    status: Option<u16>,
+    within: Option<u64>,
+}
+
+impl ExpectedRequest {
+    fn new(world: &mut Civilization, captures: &[String]) -> Self {
+        let [server, not, pattern, times, method, status, within] = captures else {
+            panic!("Expected 7 captures, found {}", captures.len());
+        };
+        let optional = |capture: &String| Some(capture.clone()).filter(|c| !c.is_empty());
+        Self {
+            server: server.clone(),
+            not: !not.is_empty(),
+            pattern: UrlPattern::new(&world.process_substitutions(pattern)),
+            times: optional(times).map(|times| times.parse().expect("Count was a number")),
+            method: optional(method),
+            status: optional(status).map(|status| status.parse().expect("Status was a number")),
+            within: optional(within).map(|within| within.parse().expect("Within was a number")),
+        }
+    }
+
+    fn matches(&self, request: &ServedRequest) -> bool {
+        request.server == self.server
+            && self.pattern.matches(&request.path)
+            && self
+                .method
+                .iter()
+                .all(|method| request.method.eq_ignore_ascii_case(method))
+            && self.status.iter().all(|status| request.status == *status)
+    }
+}
+
+// HELPERS
+
+/// Requests a full URL, or a path on the default or a named server,
+/// with the scenario's request headers followed by the step's own
+async fn send_request(
+    world: &mut Civilization,
+    method: &str,
+    path: &str,
+    server: &str,
+    headers: Vec<(String, String)>,
+    body: Option<String>,
+) {
+    let url = world.server_url(path, Some(server).filter(|s| !s.is_empty()));
+    let mut all_headers = world.request_headers.clone();
+    all_headers.extend(headers);
+    let response = client::request(method, &url, &all_headers, body)
+        .await
+        .unwrap_or_else(|e| panic!("Requesting {} {} failed: {}", method, url, e));
+    world.last_response = Some(response);
+}
+
+fn last_response(world: &Civilization) -> &ClientResponse {
+    world
+        .last_response
+        .as_ref()
+        .expect("No request has been made yet — use a `When I request GET \"/path\"` step first")
+}
+
+fn add_mock_route(
+    world: &mut Civilization,
+    method: String,
+    path: String,
+    status: String,
+    body: String,
+) {
+    let route = MockRoute {
+        method: method.to_uppercase(),
+        path: world.process_substitutions(path),
+        status: match status.as_str() {
+            "" => 200,
+            status => status.parse().expect("Status was a number"),
+        },
+        body,
+    };
+    world.ensure_mock_api().add_route(route);
+}
+
+/// Body assertions check the most recent matching request
+fn last_received(world: &mut Civilization, method: &str, path: &str) -> ReceivedRequest {
+    let path = world.process_substitutions(path);
+    let received = world.ensure_mock_api().received();
+    received
+        .iter()
+        .rev()
+        .find(|request| request.matches(method, &path))
+        .cloned()
+        .unwrap_or_else(|| {
+            panic!(
+                "The mock API never received {} {}. All requests:\n{}",
+                method,
+                path,
+                list_received(&received)
+            )
+        })
+}
+
+fn parse_json_body(request: &ReceivedRequest) -> Value {
+    serde_json::from_str(&request.body).unwrap_or_else(|e| {
+        panic!(
+            "The body of {} was not valid JSON: {}\n{}",
+            request, e, request.body
+        )
+    })
+}
+
+fn list_received(received: &[ReceivedRequest]) -> String {
+    if received.is_empty() {
+        return "(none)".into();
+    }
+    received
+        .iter()
+        .map(|request| format!("  {}", request))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,2 +1,3 @@
+pub mod http_steps;
 pub mod step_definitions;
 pub mod web_steps;
@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 use std::str::FromStr;
 
-use cucumber::gherkin::Step;
+use cucumber::gherkin::{Step, Table};
 use cucumber::{given, then, when};
 use json_dotpath::DotPaths;
 use kuchiki::iter::{Descendants, Elements, Select};
@@ -49,6 +49,12 @@ fn new_env_vars(world: &mut Civilization, step: &Step) {
     }
 }
 
+#[given(regex = "^the variable (?:\"|')([^\"']*)(?:\"|') is (?:\"|')(.*)(?:\"|')$")]
+fn set_variable(world: &mut Civilization, name: String, value: String) {
+    let value = world.process_substitutions(value);
+    world.variables.insert(name, value);
+}
+
 // BINARY WHENS
 
 #[when(regex = "^I run my program$")]
@@ -71,6 +77,42 @@ fn run_custom(world: &mut Civilization, cmd: String) {
     world.run_custom(cmd);
 }
 
+/// Saves the first capture group of the pattern, or the whole match if it has no groups
+#[when(regex = "^(DEBUG )?I save (\\w+) matching /(.*)/ as (?:\"|')([^\"']*)(?:\"|')$")]
+fn save_output(
+    world: &mut Civilization,
+    debug: StepDebug,
+    stream: Stream,
+    pattern: String,
+    name: String,
+) {
+    let command = world
+        .last_command_output
+        .as_ref()
+        .expect("No command output to save from");
+    let output = match stream {
+        Stream::Stdout => &command.stdout,
+        Stream::Stderr => &command.stderr,
+    };
+    debug.log(output);
+
+    let pattern =
+        Regex::new(&pattern).unwrap_or_else(|e| panic!("Invalid pattern /{}/: {}", pattern, e));
+    let captures = pattern.captures(output).unwrap_or_else(|| {
+        panic!(
+            "Nothing matched /{}/ in the output:\n-----\n{}\n-----\n",
+            pattern, output
+        )
+    });
+    let value = captures
+        .get(1)
+        .or_else(|| captures.get(0))
+        .unwrap()
+        .as_str()
+        .to_string();
+    world.variables.insert(name, value);
+}
+
 // THENS
 
 #[then(regex = "^(DEBUG )?I should see (?:\"|')(.*)(?:\"|') in (\\w+)$")]
@@ -80,6 +122,7 @@ fn stdout_does_contain(
     expected: String,
     stream: Stream,
 ) {
+    let expected = world.process_substitutions(expected);
     match &world.last_command_output {
         Some(command) => {
             debug.log(&command.stdout);
@@ -112,6 +155,7 @@ fn file_does_contain(
     world.assert_file_exists(&filename);
     let contents = world.read_file(&filename);
     debug.log(&contents);
+    let expected = world.process_substitutions(expected);
     assert!(contents.contains(&expected));
 }
 
@@ -125,6 +169,7 @@ fn file_does_not_contain(
     world.assert_file_exists(&filename);
     let contents = world.read_file(&filename);
     debug.log(&contents);
+    let expected = world.process_substitutions(expected);
     assert!(!contents.contains(&expected));
 }
 
@@ -175,16 +220,13 @@ fn selector_attributes(
     debug.log(&contents);
     let parsed_file = parse_html_file(&contents);
     let mut last_looked_at: Option<NodeDataRef<ElementData>> = None;
+    let table = world.process_table(step.table.as_ref().expect("This step requires a table"));
 
     'nodes: for node in select_nodes(&parsed_file, &selector) {
         last_looked_at = Some(node.clone());
         let atts = node_attributes(&node);
         let attributes = atts.borrow_mut();
-        let rows = &step
-            .table
-            .as_ref()
-            .expect("This step requires a table")
-            .rows;
+        let rows = &table.rows;
         for row in rows {
             let attribute_key = normalize_table_cell(&row[0]);
             let value = match attribute_key.as_ref() {
@@ -231,15 +273,19 @@ fn json_contains_values(world: &mut Civilization, debug: StepDebug, step: &Step,
     let contents = world.read_file(&filename);
     debug.log(&contents);
     let parsed_json = parse_json_file(&contents);
+    let table = world.process_table(step.table.as_ref().expect("This step requires a table"));
+    assert_json_values(&parsed_json, &table);
+}
+
+// HELPERS
+
+/// Checks each `| dot.path | value |` row of the table against the JSON,
+/// with `int:` and `bool:` prefixed values compared as numbers and booleans
+pub(crate) fn assert_json_values(parsed_json: &Value, table: &Table) {
     let int_re = Regex::new(r"^int:(\d+)$").unwrap();
     let bool_re = Regex::new(r"^bool:(true|false)$").unwrap();
 
-    for row in &step
-        .table
-        .as_ref()
-        .expect("This step requires a table")
-        .rows
-    {
+    for row in &table.rows {
         let expected_value = normalize_table_cell(&row[1]);
         if let Some(expected_int) = int_re.captures(&expected_value) {
             let value: i64 = parsed_json
@@ -290,8 +336,6 @@ fn json_contains_values(world: &mut Civilization, debug: StepDebug, step: &Step,
     }
 }
 
-// HELPERS
-
 fn parse_json_file(json: &str) -> Value {
     serde_json::from_str(json).expect("File contained invalid JSON")
 }
@@ -314,7 +358,7 @@ fn node_attributes(node: &NodeDataRef<ElementData>) -> RefCell<Attributes> {
         .clone()
 }
 
-fn normalize_table_cell(table_value: &str) -> String {
+pub(crate) fn normalize_table_cell(table_value: &str) -> String {
     table_value.replace("\\PIPE", "|").replace("\\n", "\n")
 }
 
@@ -336,7 +380,7 @@ fn template_file(body_contents: &str) -> String {
 
 // Helpers
 
-struct StepDebug(bool);
+pub(crate) struct StepDebug(bool);
 
 impl FromStr for StepDebug {
     type Err = &'static str;
@@ -349,14 +393,14 @@ impl FromStr for StepDebug {
 }
 
 impl StepDebug {
-    fn log(&self, contents: &str) {
+    pub(crate) fn log(&self, contents: &str) {
         if self.0 {
             println!("\n\nDEBUG:\n---\n{:?}\n---\n\n", contents);
         }
     }
 }
 
-struct Not(bool);
+pub(crate) struct Not(pub(crate) bool);
 
 impl FromStr for Not {
     type Err = &'static str;
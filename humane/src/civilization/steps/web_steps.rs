@@ -1,57 +1,445 @@
-use crate::civilization::Civilization;
-use actix_files as fs;
-use actix_web::{App, HttpServer};
-use cucumber::gherkin::Step;
-use cucumber::{then, when};
-use tokio::time::{sleep, Duration};
-
-#[when(regex = "^I serve the (?:\"|')(.*)(?:\"|') directory$")]
-async fn serve_dir(world: &mut Civilization, dir: String) {
+use std::str::FromStr;
+
+use crate::civilization::browser::{
+    AccessibilityRule, ElementProperty, Interception, RecordedRequest, RequestFilter, UrlPattern,
+    WaitFor, WebStorage,
+};
+use crate::civilization::server::{
+    serve_file, wait_until_ready, Certificate, ServedRequest, StaticSite, READY_PROBE_HEADER,
+};
+use crate::civilization::steps::step_definitions::{normalize_table_cell, Not, StepDebug};
+use crate::civilization::{visual, Civilization};
+use actix_web::body::{BodySize, MessageBody};
+use actix_web::dev::Service;
+use actix_web::{web, App, HttpServer};
+use cucumber::gherkin::{Step, Table};
+use cucumber::{given, then, when};
+use regex::Regex;
+
+#[when(
+    regex = "^(DEBUG )?I serve (?:the )?(?:\"|')(.*?)(?:\"|')(?: directory)?(?: as (?:\"|')([^\"']*)(?:\"|'))?$"
+)]
+async fn serve_dir(world: &mut Civilization, debug: StepDebug, dir: String, name: String) {
+    let site = StaticSite::new(world.tmp_file_path(&dir));
+    serve_site(world, site, &name, debug).await;
+}
+
+#[when(
+    regex = "^(DEBUG )?I serve (?:the )?(?:\"|')(.*?)(?:\"|')(?: directory)?(?: as (?:\"|')([^\"']*)(?:\"|'))? with:$"
+)]
+async fn serve_dir_with_options(
+    world: &mut Civilization,
+    debug: StepDebug,
+    step: &Step,
+    dir: String,
+    name: String,
+) {
+    let mut site = StaticSite::new(world.tmp_file_path(&dir));
+    let rows = &step
+        .table
+        .as_ref()
+        .expect("This step requires a table")
+        .rows;
+    for row in rows {
+        site.configure(
+            &normalize_table_cell(&row[0]),
+            &normalize_table_cell(&row[1]),
+        )
+        .unwrap_or_else(|e| panic!("{}", e));
+    }
+    serve_site(world, site, &name, debug).await;
+}
+
+/// Serves the site on the scenario's default port, or on the port of the named server,
+/// returning once the server responds to requests
+async fn serve_site(world: &mut Civilization, site: StaticSite, name: &str, debug: StepDebug) {
+    let site = web::Data::new(site);
     let mut attempts = 0;
-    let mut running = false;
-    while !running && attempts < 5 {
-        let port = world.ensure_port();
-        let dir = world.tmp_file_path(&dir);
-        match HttpServer::new(move || {
-            App::new().service(fs::Files::new("/", &dir).index_file("index.html"))
-        })
-        .bind(("127.0.0.1", port))
-        {
+    let mut bound_port = None;
+    while bound_port.is_none() && attempts < 5 {
+        let port = match name {
+            "" => world.ensure_port(),
+            name => world.ensure_named_port(name),
+        };
+        let app_site = site.clone();
+        let log = world.served_requests.clone();
+        let server = name.to_string();
+        let server = HttpServer::new(move || {
+            let log = log.clone();
+            let server = server.clone();
+            App::new()
+                .app_data(app_site.clone())
+                .wrap_fn(move |req, srv| {
+                    let method = req.method().to_string();
+                    let path = req
+                        .uri()
+                        .path_and_query()
+                        .map(|path| path.to_string())
+                        .unwrap_or_default();
+                    let probe = req.headers().contains_key(READY_PROBE_HEADER);
+                    let (log, server) = (log.clone(), server.clone());
+                    let response = srv.call(req);
+                    async move {
+                        let response = response.await?;
+                        let bytes = match response.response().body().size() {
+                            BodySize::Sized(bytes) => bytes,
+                            _ => 0,
+                        };
+                        if probe {
+                            return Ok(response);
+                        }
+                        log.lock().unwrap().push(ServedRequest {
+                            server,
+                            method,
+                            path,
+                            status: response.status().as_u16(),
+                            bytes,
+                        });
+                        Ok(response)
+                    }
+                })
+                .service(
+                    web::resource("/{path:.*}")
+                        .route(web::get().to(serve_file))
+                        .route(web::head().to(serve_file)),
+                )
+        });
+        let bound = match site.https {
+            true => {
+                server.bind_rustls_0_23(("127.0.0.1", port), Certificate::get().server_config())
+            }
+            false => server.bind(("127.0.0.1", port)),
+        };
+        match bound {
             Ok(bound) => {
                 let server = bound.run();
                 let handle = server.handle();
                 world.handles.push(handle);
-                world
-                    .threads
-                    .push(tokio::task::spawn(async { server.await }));
-                running = true;
+                world.threads.push(tokio::task::spawn(server));
+                bound_port = Some(port);
             }
             Err(_) => {
-                world.purge_port();
+                match name {
+                    "" => world.purge_port(),
+                    name => world.purge_named_port(name),
+                }
                 attempts += 1;
             }
         }
     }
 
-    assert!(running);
-    // Wait a beat to make sure the server is ready to roll
-    sleep(Duration::from_millis(100)).await;
+    let port = bound_port.unwrap_or_else(|| {
+        panic!(
+            "No port could be bound to serve {:?} after {} attempts",
+            site.root, attempts
+        )
+    });
+    let waited = wait_until_ready(port, site.https)
+        .await
+        .unwrap_or_else(|e| panic!("Serving {:?} failed: {}", site.root, e));
+    if site.https {
+        world.serve_securely(name).await;
+    }
+    debug.log(&format!(
+        "Serving {:?} at {}://localhost:{} (responding after {}ms)",
+        site.root,
+        if site.https { "https" } else { "http" },
+        port,
+        waited.as_millis()
+    ));
+}
+
+#[given(regex = "^the viewport is (\\d+)x(\\d+)$")]
+async fn set_viewport(world: &mut Civilization, width: u32, height: u32) {
+    let browser = world.ensure_browser().await;
+    browser
+        .set_viewport(width, height)
+        .await
+        .expect("Viewport could not be set");
+}
+
+#[given(regex = "^I emulate a mobile device$")]
+async fn emulate_mobile(world: &mut Civilization) {
+    let browser = world.ensure_browser().await;
+    browser
+        .emulate_mobile()
+        .await
+        .expect("Mobile device could not be emulated");
+}
+
+#[given(regex = "^the browser prefers (dark|light) mode$")]
+async fn prefers_color_scheme(world: &mut Civilization, scheme: String) {
+    let browser = world.ensure_browser().await;
+    browser
+        .set_media_feature("prefers-color-scheme", &scheme)
+        .await
+        .expect("Color scheme could not be emulated");
+}
+
+#[given(regex = "^the browser prefers reduced motion$")]
+async fn prefers_reduced_motion(world: &mut Civilization) {
+    let browser = world.ensure_browser().await;
+    browser
+        .set_media_feature("prefers-reduced-motion", "reduce")
+        .await
+        .expect("Reduced motion could not be emulated");
+}
+
+#[given(regex = "^the cookie (?:\"|')([^\"']*)(?:\"|') is (?:\"|')(.*)(?:\"|')$")]
+async fn set_cookie(world: &mut Civilization, name: String, value: String) {
+    let browser = world.ensure_browser().await;
+    browser
+        .set_cookie(&name, &value)
+        .await
+        .unwrap_or_else(|e| panic!("Cookie could not be set: {}", e));
+}
+
+#[given(
+    regex = "^the (local|session) storage item (?:\"|')([^\"']*)(?:\"|') is (?:\"|')(.*)(?:\"|')$"
+)]
+async fn set_storage_item(
+    world: &mut Civilization,
+    storage: WebStorage,
+    key: String,
+    value: String,
+) {
+    let browser = world.ensure_browser().await;
+    browser
+        .set_storage_item(storage, &key, &value)
+        .await
+        .unwrap_or_else(|e| panic!("Storage item could not be set: {}", e));
+}
+
+#[when(regex = "^I clear the cookies$")]
+async fn clear_cookies(world: &mut Civilization) {
+    let browser = world.ensure_browser().await;
+    browser
+        .clear_cookies()
+        .await
+        .unwrap_or_else(|e| panic!("Cookies could not be cleared: {}", e));
+}
+
+#[when(regex = "^I clear the (local|session) storage$")]
+async fn clear_storage(world: &mut Civilization, storage: WebStorage) {
+    let browser = world.ensure_browser().await;
+    browser
+        .clear_storage(storage)
+        .await
+        .unwrap_or_else(|e| panic!("Storage could not be cleared: {}", e));
+}
+
+#[given(regex = "^the browser blocks requests to (?:\"|')([^\"']*)(?:\"|')$")]
+async fn block_requests(world: &mut Civilization, pattern: String) {
+    let browser = world.ensure_browser().await;
+    browser
+        .intercept_requests(UrlPattern::new(&pattern), Interception::Block)
+        .await
+        .expect("Requests could not be blocked");
+}
+
+#[given(
+    regex = "^the browser responds to (?:\"|')([^\"']*)(?:\"|')(?: with the status (\\d+))?(?: with the file (?:\"|')(.*)(?:\"|'))?$"
+)]
+async fn stub_requests_with_file(
+    world: &mut Civilization,
+    step: &Step,
+    pattern: String,
+    status: String,
+    file: String,
+) {
+    let (body, content_type) = match file.as_str() {
+        "" => (vec![], content_type_of(&pattern)),
+        file => (
+            std::fs::read(world.tmp_file_path(file))
+                .unwrap_or_else(|e| panic!("Fixture {} could not be read: {}", file, e)),
+            content_type_of(file),
+        ),
+    };
+    let headers = step.table.as_ref().map(|table| world.process_table(table));
+    stub_requests(world, &pattern, &status, body, content_type, headers).await;
+}
+
+#[given(
+    regex = "^the browser responds to (?:\"|')([^\"']*)(?:\"|')(?: with the status (\\d+))? with:$"
+)]
+async fn stub_requests_with_docstring(
+    world: &mut Civilization,
+    step: &Step,
+    pattern: String,
+    status: String,
+) {
+    match &step.docstring {
+        Some(contents) => {
+            let body = contents.as_bytes().to_vec();
+            let content_type = content_type_of(&pattern);
+            stub_requests(world, &pattern, &status, body, content_type, None).await;
+        }
+        None => panic!("`{}` step expected a docstring", step.value),
+    }
+}
+
+/// Stubs send a `Content-Type` and any headers from the step's table, which can replace it
+async fn stub_requests(
+    world: &mut Civilization,
+    pattern: &str,
+    status: &str,
+    body: Vec<u8>,
+    content_type: String,
+    table: Option<Table>,
+) {
+    let status = match status {
+        "" => 200,
+        status => status.parse().expect("Status was a number"),
+    };
+    let mut headers: Vec<(String, String)> = table
+        .iter()
+        .flat_map(|table| &table.rows)
+        .map(|row| match row.as_slice() {
+            [name, value] => (name.clone(), value.clone()),
+            _ => panic!("Response headers should be rows of | name | value |"),
+        })
+        .collect();
+    if !headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+    {
+        headers.insert(0, ("Content-Type".into(), content_type));
+    }
+    let browser = world.ensure_browser().await;
+    browser
+        .intercept_requests(
+            UrlPattern::new(pattern),
+            Interception::Respond {
+                status,
+                headers,
+                body,
+            },
+        )
+        .await
+        .expect("Requests could not be intercepted");
 }
 
-#[when(regex = "^I load (?:\"|')(.*)(?:\"|')$")]
-async fn load_page(world: &mut Civilization, path: String) {
-    let url = format!("http://localhost:{}{}", world.ensure_port(), path);
+#[when(regex = "^I load (?:\"|')(.*?)(?:\"|')(?: from (?:\"|')([^\"']*)(?:\"|'))?$")]
+async fn load_page(world: &mut Civilization, path: String, server: String) {
+    let url = world.server_url(&path, Some(server.as_str()).filter(|s| !s.is_empty()));
     let browser = world.ensure_browser().await;
     browser.load_page(&url).await.expect("Loading URL failed");
 }
 
-#[when(regex = "^I click (?:\"|')(.*)(?:\"|')$")]
-async fn click_selector(world: &mut Civilization, selector: String) {
+#[when(
+    regex = "^I open (?:\"|')(.*?)(?:\"|')(?: from (?:\"|')([^\"']*)(?:\"|'))? in a new tab (?:\"|')(.*)(?:\"|')$"
+)]
+async fn open_tab(world: &mut Civilization, path: String, server: String, name: String) {
+    let url = world.server_url(&path, Some(server.as_str()).filter(|s| !s.is_empty()));
+    let browser = world.ensure_browser().await;
+    browser
+        .open_tab(&name, &url)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[when(regex = "^I switch to the tab (?:\"|')(.*)(?:\"|')$")]
+async fn switch_tab(world: &mut Civilization, name: String) {
+    let browser = world.ensure_browser().await;
+    browser
+        .switch_tab(&name)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[when(regex = "^I go (back|forward)(?: within (\\d+)ms)?$")]
+async fn go_through_history(world: &mut Civilization, direction: String, within: Within) {
+    let offset = match direction.as_str() {
+        "back" => -1,
+        _ => 1,
+    };
+    let browser = world.ensure_browser().await;
+    browser
+        .go_through_history(offset, within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[when(regex = "^I reload the page(?: within (\\d+)ms)?$")]
+async fn reload_page(world: &mut Civilization, within: Within) {
+    let browser = world.ensure_browser().await;
+    browser
+        .reload(within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[when(regex = "^I click (?:\"|')(.*)(?:\"|')(?: within (\\d+)ms)?$")]
+async fn click_selector(world: &mut Civilization, selector: String, within: Within) {
+    let browser = world.ensure_browser().await;
+    browser
+        .click(&selector, within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[when(regex = "^I click (?:\"|')(.*)(?:\"|') and wait for navigation(?: within (\\d+)ms)?$")]
+async fn click_and_navigate(world: &mut Civilization, selector: String, within: Within) {
+    let browser = world.ensure_browser().await;
+    browser
+        .click_and_wait_for_navigation(&selector, within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+/// As with `should be hidden`, a missing element isn't hidden unless the step says `hidden or not exist`
+#[when(
+    regex = "^I wait for (?:\"|')(.*)(?:\"|') to be (visible|hidden|hidden or not exist|detached)(?: within (\\d+)ms)?$"
+)]
+async fn wait_for_selector(
+    world: &mut Civilization,
+    selector: String,
+    state: String,
+    within: Within,
+) {
+    let condition = match state.as_str() {
+        "visible" => WaitFor::Visible(selector),
+        "hidden" => WaitFor::Hidden(selector),
+        "hidden or not exist" => WaitFor::HiddenOrMissing(selector),
+        _ => WaitFor::Detached(selector),
+    };
+    let browser = world.ensure_browser().await;
+    browser
+        .wait_for(&condition, within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[when(regex = "^I wait for the text (?:\"|')(.*)(?:\"|') to appear(?: within (\\d+)ms)?$")]
+async fn wait_for_text(world: &mut Civilization, text: String, within: Within) {
+    let browser = world.ensure_browser().await;
+    browser
+        .wait_for(&WaitFor::Text(text), within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[when(regex = "^I wait for the network to be idle(?: within (\\d+)ms)?$")]
+async fn wait_for_network_idle(world: &mut Civilization, within: Within) {
     let browser = world.ensure_browser().await;
     browser
-        .click(&selector)
+        .wait_for(&WaitFor::NetworkIdle, within.0)
         .await
-        .expect("Selector did not exist");
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[when(regex = "^I wait for the script to return a truthy value(?: within (\\d+)ms)?:$")]
+async fn wait_for_script(world: &mut Civilization, step: &Step, within: Within) {
+    match &step.docstring {
+        Some(contents) => {
+            let browser = world.ensure_browser().await;
+            browser
+                .wait_for(&WaitFor::Truthy(contents.clone()), within.0)
+                .await
+                .unwrap_or_else(|e| panic!("{}", e));
+        }
+        None => panic!("`{}` step expected a docstring", step.value),
+    }
 }
 
 #[when(regex = "^I evaluate:$")]
@@ -65,23 +453,499 @@ async fn eval_js(world: &mut Civilization, step: &Step) {
     }
 }
 
-#[then(regex = "^The selector (?:\"|')(.*)(?:\"|') should exist$")]
-async fn selector_exists(world: &mut Civilization, selector: String) {
+#[then(regex = "^The selector (?:\"|')(.*)(?:\"|') should exist(?: within (\\d+)ms)?$")]
+async fn selector_exists(world: &mut Civilization, selector: String, within: Within) {
+    let browser = world.ensure_browser().await;
+    browser
+        .selector_exists(&selector, within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+/// Compares the element's HTML exactly, whitespace included, unlike `should have the html`
+#[then(
+    regex = "^The selector (?:\"|')(.*)(?:\"|') should contain (?:\"|')(.*)(?:\"|')(?: within (\\d+)ms)?$"
+)]
+async fn selector_contains(
+    world: &mut Civilization,
+    selector: String,
+    contents: String,
+    within: Within,
+) {
+    let browser = world.ensure_browser().await;
+    browser
+        .expect_element(
+            &selector,
+            &ElementProperty::Html,
+            &format!("to equal {:?}", contents),
+            within.0,
+            |html: &Option<String>| html.as_deref() == Some(&contents),
+        )
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+/// `have` compares the value with its surrounding whitespace trimmed, while `contain` finds the text anywhere in it
+#[then(
+    regex = "^The selector (?:\"|')(.*)(?:\"|') should (contain|have) the (text|html) (?:\"|')(.*)(?:\"|')(?: within (\\d+)ms)?$"
+)]
+async fn selector_has_text(
+    world: &mut Civilization,
+    selector: String,
+    comparison: String,
+    kind: String,
+    expected: String,
+    within: Within,
+) {
+    let property = match kind.as_str() {
+        "text" => ElementProperty::Text,
+        _ => ElementProperty::Html,
+    };
+    let contains = comparison == "contain";
+    let expectation = match contains {
+        true => format!("to contain {:?}", expected),
+        false => format!("to equal {:?}", expected),
+    };
+
+    let browser = world.ensure_browser().await;
+    browser
+        .expect_element(
+            &selector,
+            &property,
+            &expectation,
+            within.0,
+            |value: &Option<String>| match value {
+                Some(value) if contains => value.contains(&expected),
+                Some(value) => value.trim() == expected,
+                None => false,
+            },
+        )
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[then(
+    regex = "^The selector (?:\"|')(.*)(?:\"|') should match the (text|html) /(.*)/(?: within (\\d+)ms)?$"
+)]
+async fn selector_matches_text(
+    world: &mut Civilization,
+    selector: String,
+    kind: String,
+    pattern: String,
+    within: Within,
+) {
+    let property = match kind.as_str() {
+        "text" => ElementProperty::Text,
+        _ => ElementProperty::Html,
+    };
+    let re = Regex::new(&pattern).unwrap_or_else(|e| panic!("Invalid regex /{}/: {}", pattern, e));
+
+    let browser = world.ensure_browser().await;
+    browser
+        .expect_element(
+            &selector,
+            &property,
+            &format!("to match /{}/", pattern),
+            within.0,
+            |value: &Option<String>| value.as_deref().is_some_and(|v| re.is_match(v)),
+        )
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[then(
+    regex = "^The selector (?:\"|')(.*)(?:\"|') should (not )?have the attribute (?:\"|')([^\"']*)(?:\"|')(?: within (\\d+)ms)?$"
+)]
+async fn selector_has_attribute(
+    world: &mut Civilization,
+    selector: String,
+    negation: Not,
+    attribute: String,
+    within: Within,
+) {
+    let expectation = match negation.0 {
+        true => "to be absent",
+        false => "to be present",
+    };
+
+    let browser = world.ensure_browser().await;
+    browser
+        .expect_element(
+            &selector,
+            &ElementProperty::Attribute(attribute),
+            expectation,
+            within.0,
+            |value: &Option<String>| value.is_some() != negation.0,
+        )
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[then(
+    regex = "^The selector (?:\"|')(.*)(?:\"|') should have the (attribute|style) (?:\"|')([^\"']*)(?:\"|') with the value (?:\"|')(.*)(?:\"|')(?: within (\\d+)ms)?$"
+)]
+async fn selector_has_value(
+    world: &mut Civilization,
+    selector: String,
+    kind: String,
+    name: String,
+    expected: String,
+    within: Within,
+) {
+    let property = match kind.as_str() {
+        "attribute" => ElementProperty::Attribute(name),
+        _ => ElementProperty::Style(name),
+    };
+
+    let browser = world.ensure_browser().await;
+    browser
+        .expect_element(
+            &selector,
+            &property,
+            &format!("to equal {:?}", expected),
+            within.0,
+            |value: &Option<String>| value.as_deref() == Some(&expected),
+        )
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+/// A missing element is neither visible nor hidden, unless the step allows it with `hidden or not exist`
+#[then(
+    regex = "^The selector (?:\"|')(.*)(?:\"|') should be (visible|hidden|hidden or not exist)(?: within (\\d+)ms)?$"
+)]
+async fn selector_is_visible(
+    world: &mut Civilization,
+    selector: String,
+    state: String,
+    within: Within,
+) {
+    let browser = world.ensure_browser().await;
+    browser
+        .expect_element(
+            &selector,
+            &ElementProperty::Visible,
+            &format!("to be {}", state),
+            within.0,
+            |value: &Option<bool>| match state.as_str() {
+                "visible" => *value == Some(true),
+                "hidden" => *value == Some(false),
+                _ => *value != Some(true),
+            },
+        )
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[then(regex = "^The selector (?:\"|')(.*)(?:\"|') should (not )?be focused(?: within (\\d+)ms)?$")]
+async fn selector_is_focused(
+    world: &mut Civilization,
+    selector: String,
+    negation: Not,
+    within: Within,
+) {
+    let expectation = match negation.0 {
+        true => "to be unfocused",
+        false => "to be focused",
+    };
+
+    let browser = world.ensure_browser().await;
+    browser
+        .expect_element(
+            &selector,
+            &ElementProperty::Focused,
+            expectation,
+            within.0,
+            |value: &Option<bool>| value.is_some_and(|focused| focused != negation.0),
+        )
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[then(
+    regex = "^There should be (\\d+) elements? matching (?:\"|')(.*)(?:\"|')(?: within (\\d+)ms)?$"
+)]
+async fn selector_count(world: &mut Civilization, count: usize, selector: String, within: Within) {
     let browser = world.ensure_browser().await;
     browser
-        .selector_exists(&selector)
+        .expect_element(
+            &selector,
+            &ElementProperty::Count,
+            &format!("to be {}", count),
+            within.0,
+            |value: &usize| *value == count,
+        )
         .await
-        .expect("Selector loaded");
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[then(regex = "^the page should look like (?:\"|')(.*)(?:\"|')(?: within (\\d+(?:\\.\\d+)?)%)?$")]
+async fn page_looks_like(world: &mut Civilization, baseline: String, threshold_percent: String) {
+    let options = world.options();
+    let threshold_percent = match threshold_percent.as_str() {
+        "" => options.screenshot_threshold,
+        percent => percent.parse::<f64>().expect("Threshold was a number"),
+    };
+    let baseline_path = std::env::current_dir().unwrap().join(baseline);
+
+    let browser = world.ensure_browser().await;
+    let screenshot = browser.screenshot().await.expect("Screenshot failed");
+    visual::assert_baseline(
+        &screenshot,
+        &baseline_path,
+        threshold_percent,
+        options.update_snapshots,
+    );
 }
 
-#[then(regex = "^The selector (?:\"|')(.*)(?:\"|') should contain (?:\"|')(.*)(?:\"|')$")]
-async fn selector_contains(world: &mut Civilization, selector: String, contents: String) {
+#[then(
+    regex = "^the cookie (?:\"|')([^\"']*)(?:\"|') should (?:be (?:\"|')(.*)(?:\"|')|(not exist))(?: within (\\d+)ms)?$"
+)]
+async fn cookie_is(
+    world: &mut Civilization,
+    name: String,
+    value: String,
+    not_exist: String,
+    within: Within,
+) {
+    let expected = Some(value.as_str()).filter(|_| not_exist.is_empty());
     let browser = world.ensure_browser().await;
-    let found_contents = browser
-        .contents(&selector)
+    browser
+        .expect_cookie(&name, expected, within.0)
         .await
-        .expect("Selector does not exist");
-    assert_eq!(found_contents, contents);
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[then(
+    regex = "^the (local|session) storage item (?:\"|')([^\"']*)(?:\"|') should (?:be (?:\"|')(.*)(?:\"|')|(not exist))(?: within (\\d+)ms)?$"
+)]
+async fn storage_item_is(
+    world: &mut Civilization,
+    storage: WebStorage,
+    key: String,
+    value: String,
+    not_exist: String,
+    within: Within,
+) {
+    let expected = Some(value.as_str()).filter(|_| not_exist.is_empty());
+    let browser = world.ensure_browser().await;
+    browser
+        .expect_storage_item(storage, &key, expected, within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+}
+
+#[then(
+    regex = "^the page should have requested (?:\"|')([^\"']*)(?:\"|')(?: (\\d+) times?)?(?: with the method (?:\"|')(\\w+)(?:\"|'))?(?: with the status (\\d+))?(?: within (\\d+)ms)?$"
+)]
+async fn page_requested(
+    world: &mut Civilization,
+    pattern: String,
+    times: String,
+    method: String,
+    status: String,
+    within: Within,
+) {
+    let filter = request_filter(&pattern, &method, &status);
+    let times: Option<usize> = match times.as_str() {
+        "" => None,
+        times => Some(times.parse().expect("Count was a number")),
+    };
+    let browser = world.ensure_browser().await;
+    let expectation = match times {
+        Some(times) => format!("{} time(s)", times),
+        None => "at least once".into(),
+    };
+    if let Err(timeout) = browser
+        .wait_for_requests(&filter, within.0, |requests| match times {
+            Some(times) => requests.len() == times,
+            None => !requests.is_empty(),
+        })
+        .await
+    {
+        panic!(
+            "Expected the page to request {} {}, but after {}ms it had requested it {} time(s). All requests:\n{}",
+            filter,
+            expectation,
+            timeout.waited.as_millis(),
+            timeout.last_value.map(|r| r.len()).unwrap_or_default(),
+            list_requests(&browser.requests())
+        );
+    }
+}
+
+#[then(
+    regex = "^the page should not have requested (?:\"|')([^\"']*)(?:\"|')(?: with the method (?:\"|')(\\w+)(?:\"|'))?(?: with the status (\\d+))?$"
+)]
+async fn page_did_not_request(
+    world: &mut Civilization,
+    pattern: String,
+    method: String,
+    status: String,
+) {
+    let filter = request_filter(&pattern, &method, &status);
+    let browser = world.ensure_browser().await;
+    let requests = browser.requests();
+    let matching: Vec<_> = requests
+        .iter()
+        .filter(|request| filter.matches(request))
+        .cloned()
+        .collect();
+    if !matching.is_empty() {
+        panic!(
+            "Expected the page not to request {}, but it did:\n{}",
+            filter,
+            list_requests(&matching)
+        );
+    }
+}
+
+fn request_filter(pattern: &str, method: &str, status: &str) -> RequestFilter {
+    RequestFilter {
+        url: UrlPattern::new(pattern),
+        method: Some(method.to_string()).filter(|m| !m.is_empty()),
+        status: match status {
+            "" => None,
+            status => Some(status.parse().expect("Status was a number")),
+        },
+    }
+}
+
+fn list_requests(requests: &[RecordedRequest]) -> String {
+    if requests.is_empty() {
+        return "(none)".into();
+    }
+    requests
+        .iter()
+        .map(|r| format!("  {}", r))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Stubbed responses are typed by the fixture they came from, or else by the URL they answer
+fn content_type_of(path: &str) -> String {
+    mime_guess::from_path(path.split(['?', '*']).next().unwrap_or(path))
+        .first_or_octet_stream()
+        .to_string()
+}
+
+#[then(
+    regex = "^the page should have no accessibility violations(?: in (?:\"|')([^\"']*)(?:\"|'))?(?: for the rules? (?:\"|')([^\"']*)(?:\"|'))?$"
+)]
+async fn no_accessibility_violations(world: &mut Civilization, scope: String, rules: String) {
+    let rules: Vec<_> = match rules.as_str() {
+        "" => AccessibilityRule::ALL.to_vec(),
+        rules => rules
+            .split(',')
+            .map(|rule| {
+                AccessibilityRule::from_name(rule.trim()).unwrap_or_else(|e| panic!("{}", e))
+            })
+            .collect(),
+    };
+    let scope = Some(scope.as_str()).filter(|s| !s.is_empty());
+
+    let browser = world.ensure_browser().await;
+    let violations = browser
+        .accessibility_violations(scope, &rules)
+        .await
+        .unwrap_or_else(|e| panic!("Accessibility audit failed: {}", e));
+    if !violations.is_empty() {
+        panic!(
+            "Expected no accessibility violations{}, but found {}:\n\n{}",
+            scope.map(|s| format!(" in {}", s)).unwrap_or_default(),
+            violations.len(),
+            violations
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
+
+#[then(regex = "^the page should load in under (\\d+)ms(?: within (\\d+)ms)?$")]
+async fn page_load_time(world: &mut Civilization, limit: f64, within: Within) {
+    let browser = world.ensure_browser().await;
+    let load = browser
+        .page_load(within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+    if load.load_time >= limit {
+        panic!(
+            "Expected the page to load in under {}ms, but it took {:.0}ms",
+            limit, load.load_time
+        );
+    }
+}
+
+#[then(
+    regex = "^the page should transfer under (\\d+(?:\\.\\d+)?) ?(B|kB|KB|KiB|MB|MiB)(?: within (\\d+)ms)?$"
+)]
+async fn page_transfer_size(world: &mut Civilization, limit: f64, unit: String, within: Within) {
+    let bytes = limit
+        * match unit.as_str() {
+            "B" => 1.0,
+            "kB" | "KB" => 1000.0,
+            "KiB" => 1024.0,
+            "MB" => 1000.0 * 1000.0,
+            _ => 1024.0 * 1024.0,
+        };
+    let browser = world.ensure_browser().await;
+    let load = browser
+        .page_load(within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+    if load.transferred_bytes >= bytes {
+        panic!(
+            "Expected the page to transfer under {}{}, but it transferred {} bytes over {} request(s)",
+            limit,
+            unit,
+            load.transferred_bytes,
+            load.requests.len()
+        );
+    }
+}
+
+#[then(regex = "^the page should make at most (\\d+) requests?(?: within (\\d+)ms)?$")]
+async fn page_request_count(world: &mut Civilization, limit: usize, within: Within) {
+    let browser = world.ensure_browser().await;
+    let load = browser
+        .page_load(within.0)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+    if load.requests.len() > limit {
+        panic!(
+            "Expected the page to make at most {} request(s), but it made {}:\n{}",
+            limit,
+            load.requests.len(),
+            load.requests
+                .iter()
+                .map(|url| format!("  {}", url))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
+
+#[then(
+    regex = "^the page metric (?:\"|')(\\w+)(?:\"|') should be (under|over) (\\d+(?:\\.\\d+)?)$"
+)]
+async fn page_metric(world: &mut Civilization, name: String, comparison: String, limit: f64) {
+    let browser = world.ensure_browser().await;
+    let value = browser
+        .performance_metric(&name)
+        .await
+        .unwrap_or_else(|e| panic!("{}", e));
+    let passes = match comparison.as_str() {
+        "under" => value < limit,
+        _ => value > limit,
+    };
+    if !passes {
+        panic!(
+            "Expected the page metric {} to be {} {}, but it was {}",
+            name, comparison, limit, value
+        );
+    }
 }
 
 #[then(regex = "^There should be no logs$")]
@@ -95,3 +959,16 @@ async fn no_logs(world: &mut Civilization) {
         );
     }
 }
+
+/// The optional ` within 500ms` suffix that overrides how long a web step waits
+pub(crate) struct Within(pub(crate) Option<u64>);
+
+impl FromStr for Within {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Ok(Within(None)),
+            ms => Ok(Within(Some(ms.parse()?))),
+        }
+    }
+}
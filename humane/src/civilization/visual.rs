@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use image::{Rgba, RgbaImage};
+
+/// How far apart any channel of two pixels can be before the pixel counts as changed.
+/// Absorbs the anti-aliasing noise between otherwise identical renders.
+const CHANNEL_TOLERANCE: u8 = 8;
+
+pub struct VisualDiff {
+    pub changed_pixels: u64,
+    pub total_pixels: u64,
+    pub diff: RgbaImage,
+}
+
+impl VisualDiff {
+    pub fn changed_percent(&self) -> f64 {
+        if self.total_pixels == 0 {
+            return 0.0;
+        }
+        self.changed_pixels as f64 / self.total_pixels as f64 * 100.0
+    }
+}
+
+/// Compares two images pixel by pixel, producing a diff image
+/// with unchanged pixels faded out and changed pixels in red.
+/// Any area not covered by both images counts as changed.
+pub fn compare(baseline: &RgbaImage, actual: &RgbaImage) -> VisualDiff {
+    let width = baseline.width().max(actual.width());
+    let height = baseline.height().max(actual.height());
+    let mut diff = RgbaImage::new(width, height);
+    let mut changed_pixels = 0;
+
+    for (x, y, pixel) in diff.enumerate_pixels_mut() {
+        let expected = baseline.get_pixel_checked(x, y);
+        let found = actual.get_pixel_checked(x, y);
+        match (expected, found) {
+            (Some(expected), Some(found)) if pixels_match(expected, found) => {
+                *pixel = faded(expected);
+            }
+            _ => {
+                *pixel = Rgba([255, 0, 0, 255]);
+                changed_pixels += 1;
+            }
+        }
+    }
+
+    VisualDiff {
+        changed_pixels,
+        total_pixels: width as u64 * height as u64,
+        diff,
+    }
+}
+
+/// Checks a PNG screenshot against the baseline image on disk,
+/// allowing `threshold_percent` of its pixels to differ, or replaces the baseline when `update` is set.
+/// On a mismatch, the screenshot and a diff image are written alongside the baseline.
+pub fn assert_baseline(
+    screenshot: &[u8],
+    baseline_path: &Path,
+    threshold_percent: f64,
+    update: bool,
+) {
+    if update {
+        fs::create_dir_all(baseline_path.parent().unwrap()).unwrap();
+        fs::write(baseline_path, screenshot).expect("Baseline could not be written");
+        return;
+    }
+
+    let actual_path = sibling_path(baseline_path, "actual");
+    if !baseline_path.exists() {
+        fs::create_dir_all(baseline_path.parent().unwrap()).unwrap();
+        fs::write(&actual_path, screenshot).expect("Screenshot could not be written");
+        panic!(
+            "No baseline exists at {:?}. The page was saved to {:?} — run Humane with --update-snapshots to accept it as the baseline.",
+            baseline_path, actual_path
+        );
+    }
+
+    let baseline = image::open(baseline_path)
+        .unwrap_or_else(|e| panic!("Baseline {:?} could not be read: {}", baseline_path, e))
+        .to_rgba8();
+    let actual = image::load_from_memory(screenshot)
+        .expect("Screenshot was a valid image")
+        .to_rgba8();
+
+    let result = compare(&baseline, &actual);
+    if result.changed_percent() > threshold_percent {
+        let diff_path = sibling_path(baseline_path, "diff");
+        fs::write(&actual_path, screenshot).expect("Screenshot could not be written");
+        result
+            .diff
+            .save(&diff_path)
+            .expect("Diff could not be written");
+        panic!(
+            "The page differs from {:?} by {:.3}% ({} of {} pixels), above the allowed {:.3}%.\nBaseline size: {}x{}, page size: {}x{}\nThe page was saved to {:?} and the differences to {:?}",
+            baseline_path,
+            result.changed_percent(),
+            result.changed_pixels,
+            result.total_pixels,
+            threshold_percent,
+            baseline.width(),
+            baseline.height(),
+            actual.width(),
+            actual.height(),
+            actual_path,
+            diff_path
+        );
+    }
+}
+
+fn pixels_match(a: &Rgba<u8>, b: &Rgba<u8>) -> bool {
+    a.0.iter()
+        .zip(b.0.iter())
+        .all(|(a, b)| a.abs_diff(*b) <= CHANNEL_TOLERANCE)
+}
+
+fn faded(pixel: &Rgba<u8>) -> Rgba<u8> {
+    let [r, g, b, _] = pixel.0;
+    let luma = ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8;
+    let faded = 255 - (255 - luma) / 4;
+    Rgba([faded, faded, faded, 255])
+}
+
+/// `baseline.png` -> `baseline.<suffix>.png`
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .expect("Baseline has a utf-8 filename");
+    path.with_file_name(format!("{}.{}.png", stem, suffix))
+}
@@ -1,12 +1,17 @@
 use std::env;
+use std::path::PathBuf;
 
-use cucumber::{gherkin::Scenario, Cucumber, WorldInit};
+use cucumber::cli::{self, Parser};
+use cucumber::{gherkin::Scenario, Cucumber};
+use regex::Regex;
 
 use civilization::Civilization;
 use options::RobotHumaneConfig;
+use parser::FeatureParser;
 
 mod civilization;
 pub mod options;
+mod parser;
 
 pub struct Humane {
     options: RobotHumaneConfig,
@@ -18,11 +23,27 @@ impl Humane {
     }
 
     pub async fn go(&mut self) {
+        civilization::configure(self.options.clone());
+
         let has_tag = |sc: &Scenario, tag| sc.tags.iter().any(|t| t == tag);
 
-        let r = Cucumber::new()
-            .steps(Civilization::collection())
+        // Humane's flags are parsed by Humane, so cucumber mustn't parse the CLI itself
+        let mut cli = cli::Opts::<_, _, _, cli::Empty>::parse_from(["humane"]);
+        cli.re_filter = self.options.name.as_ref().map(|name| {
+            Regex::new(name).unwrap_or_else(|e| panic!("Invalid --name regex {}: {}", name, e))
+        });
+
+        // The default parser is replaced, so its input type has to be named up front
+        let r = Cucumber::<Civilization, _, PathBuf, _, _>::new()
+            .with_parser(FeatureParser)
+            .with_cli(cli)
+            .steps(civilization::steps())
             .max_concurrent_scenarios(Some(4))
+            .before(|_, _, scenario, world| {
+                Box::pin(async move {
+                    world.use_examples(scenario);
+                })
+            })
             .after(|_, _, _, maybe_world| {
                 Box::pin(async move {
                     if let Some(world) = maybe_world {
@@ -47,6 +68,9 @@ impl Humane {
                 }
             })
             .await;
+        civilization::close_browsers().await;
+        civilization::write_coverage();
+
         if r.parsing_errors > 0
             || r.failed_hooks > 0
             || r.scenarios.failed > 0
@@ -1,6 +1,5 @@
 use std::time::Instant;
 
-use twelf::reexports::clap::CommandFactory;
 use twelf::Layer;
 
 use humane::options::{HumanHumaneConfig, RobotHumaneConfig};
@@ -12,7 +11,7 @@ const CONFIGS: &[&str] = &["humane.json", "humane.yml", "humane.yaml", "humane.t
 async fn main() {
     let start = Instant::now();
 
-    let matches = HumanHumaneConfig::command().get_matches();
+    let matches = HumanHumaneConfig::command_with_sections().get_matches();
 
     let mut config_layers = vec![];
 
@@ -1,6 +1,7 @@
 use anyhow::Result;
-use clap::Parser;
-use std::{env, path::PathBuf};
+use clap::{Arg, Command, CommandFactory, Parser};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, env, path::PathBuf};
 use twelf::config;
 
 #[config]
@@ -22,18 +23,134 @@ pub struct HumanHumaneConfig {
     )]
     #[clap(required = false)]
     pub name: Option<String>,
+
+    #[clap(
+        long,
+        help = "Write new baselines for visual comparisons instead of asserting against the existing ones"
+    )]
+    #[serde(default)]
+    pub update_snapshots: bool,
+
+    #[clap(
+        long,
+        help = "The percentage of pixels (0 - 100) allowed to differ from a visual baseline. Defaults to 0"
+    )]
+    #[clap(required = false)]
+    #[serde(default = "defaults::default_screenshot_threshold")]
+    pub screenshot_threshold: f64,
+
+    #[clap(
+        long,
+        help = "Launch the browser with a visible window. Overrides the browser.headed option"
+    )]
+    #[serde(default)]
+    pub headed: bool,
+
+    #[clap(
+        long,
+        help = "Milliseconds to pause after each browser interaction. Overrides the browser.slow_mo option"
+    )]
+    #[clap(required = false)]
+    pub slow_mo: Option<u64>,
+
+    #[clap(
+        long,
+        help = "Collect JavaScript coverage from browser pages into an LCOV (.info / .lcov) or Istanbul (.json) file. Overrides the browser.coverage option"
+    )]
+    #[clap(required = false)]
+    pub coverage: Option<PathBuf>,
+
+    #[clap(skip)]
+    #[serde(default)]
+    pub browser: HumaneBrowserConfig,
+
+    /// Values available to every scenario as `{{name}}` substitutions
+    #[clap(skip)]
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+
+    /// Extra steps that run a script
+    #[clap(skip)]
+    #[serde(default)]
+    pub steps: Vec<HumaneScriptStep>,
+}
+
+/// Fields that can only be set from a configuration file
+const CONFIG_SECTIONS: [&str; 3] = ["browser", "variables", "steps"];
+
+impl HumanHumaneConfig {
+    /// twelf looks up every field in the clap matches, so sections that can only
+    /// be set from a configuration file still need a (hidden) argument to exist.
+    pub fn command_with_sections() -> Command<'static> {
+        CONFIG_SECTIONS
+            .iter()
+            .fold(Self::command(), |command, section| {
+                command.arg(
+                    Arg::new(*section)
+                        .long(section)
+                        .takes_value(true)
+                        .hide(true),
+                )
+            })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumaneBrowserConfig {
+    /// Path to a Chrome or Chromium executable. Defaults to detecting an installed browser
+    pub executable: Option<PathBuf>,
+    /// Launch the browser with a visible window rather than headless
+    pub headed: bool,
+    /// Launch without Chromium's sandbox, which is often required when running as root in containers
+    pub no_sandbox: bool,
+    /// Width of the browser window and page viewport. Defaults to 800
+    pub window_width: Option<u32>,
+    /// Height of the browser window and page viewport. Defaults to 600
+    pub window_height: Option<u32>,
+    /// Extra command line arguments to launch the browser with
+    pub args: Vec<String>,
+    /// Milliseconds to pause after each browser interaction, to follow along in headed mode
+    pub slow_mo: Option<u64>,
+    /// Milliseconds that browser steps wait for the page to reach the expected state. Defaults to 2000
+    pub wait_timeout: Option<u64>,
+    /// How many Chromium processes scenarios share, each scenario getting its own context. Defaults to 1
+    pub pool_size: Option<usize>,
+    /// File to write the JavaScript coverage of browser pages to at the end of the run,
+    /// as LCOV for `.info` / `.lcov` files or Istanbul JSON for `.json` files
+    pub coverage: Option<PathBuf>,
+}
+
+/// A step matching `pattern` that runs the `run` executable with the pattern's captures as arguments,
+/// from the scenario's temp directory, failing if it exits with a non-zero status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HumaneScriptStep {
+    /// Regex matched against the step's text, without its keyword
+    pub pattern: String,
+    /// Path to the executable, relative to where Humane is run, or a command on the PATH
+    pub run: String,
 }
 
 mod defaults {
     pub fn default_test_files() -> String {
         ".".into()
     }
+
+    pub fn default_screenshot_threshold() -> f64 {
+        0.0
+    }
 }
 
 // The configuration object used internally
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RobotHumaneConfig {
     pub test_file_root: PathBuf,
+    pub name: Option<String>,
+    pub update_snapshots: bool,
+    pub screenshot_threshold: f64,
+    pub browser: HumaneBrowserConfig,
+    pub variables: HashMap<String, String>,
+    pub steps: Vec<HumaneScriptStep>,
     pub version: &'static str,
 }
 
@@ -41,6 +158,17 @@ impl RobotHumaneConfig {
     pub fn load(config: HumanHumaneConfig) -> Result<Self> {
         Ok(Self {
             test_file_root: PathBuf::from(config.test_file_root),
+            name: config.name,
+            update_snapshots: config.update_snapshots,
+            screenshot_threshold: config.screenshot_threshold,
+            browser: HumaneBrowserConfig {
+                headed: config.headed || config.browser.headed,
+                slow_mo: config.slow_mo.or(config.browser.slow_mo),
+                coverage: config.coverage.or(config.browser.coverage),
+                ..config.browser
+            },
+            variables: config.variables,
+            steps: config.steps,
             version: env!("CARGO_PKG_VERSION"),
         })
     }
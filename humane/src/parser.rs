@@ -0,0 +1,134 @@
+use std::mem;
+use std::path::{Path, PathBuf};
+
+use cucumber::gherkin::{Feature, GherkinEnv, ParseFileError, Scenario, Step, Table};
+use cucumber::{cli, parser};
+use futures::stream;
+use regex::{Captures, Regex};
+use wax::Glob;
+
+/// Finds and parses feature files as cucumber's own parser does, but expands Scenario Outlines leniently.
+/// A `<placeholder>` that isn't a column of the Examples is left as written, so outlines can contain HTML,
+/// and each expanded scenario keeps its own row as its only example, for `{{example:column}}` substitutions.
+pub struct FeatureParser;
+
+impl<I: AsRef<Path>> cucumber::Parser<I> for FeatureParser {
+    type Cli = cli::Empty;
+    type Output = stream::Iter<std::vec::IntoIter<Result<Feature, parser::Error>>>;
+
+    fn parse(self, input: I, _: cli::Empty) -> Self::Output {
+        stream::iter(parse_features(input.as_ref()))
+    }
+}
+
+fn parse_features(root: &Path) -> Vec<Result<Feature, parser::Error>> {
+    let root = match root.canonicalize() {
+        Ok(root) => root,
+        Err(e) => {
+            return vec![Err(ParseFileError::Reading {
+                path: root.to_path_buf(),
+                source: e,
+            }
+            .into())]
+        }
+    };
+
+    let mut files: Vec<PathBuf> = if root.is_file() {
+        vec![root]
+    } else {
+        let glob = Glob::new("**/*.feature").expect("Valid glob");
+        glob.walk(&root)
+            .flatten()
+            .map(|entry| entry.path().to_path_buf())
+            .collect()
+    };
+    files.sort();
+
+    files
+        .into_iter()
+        .map(|file| {
+            Feature::parse_path(file, GherkinEnv::default())
+                .map(expand_outlines)
+                .map_err(Into::into)
+        })
+        .collect()
+}
+
+fn expand_outlines(mut feature: Feature) -> Feature {
+    let expand = |scenarios: Vec<Scenario>| -> Vec<Scenario> {
+        scenarios.into_iter().flat_map(expand_scenario).collect()
+    };
+    for rule in &mut feature.rules {
+        rule.scenarios = expand(mem::take(&mut rule.scenarios));
+    }
+    feature.scenarios = expand(mem::take(&mut feature.scenarios));
+    feature
+}
+
+/// Fills the `<placeholders>` in the text with the values found for them,
+/// leaving any placeholders without a value as written
+pub(crate) fn fill_placeholders(text: &str, value: &impl Fn(&str) -> Option<String>) -> String {
+    let placeholder = Regex::new(r"<([^>\s]+)>").unwrap();
+    placeholder
+        .replace_all(text, |captures: &Captures| {
+            value(&captures[1]).unwrap_or_else(|| captures[0].to_string())
+        })
+        .into_owned()
+}
+
+/// Fills the placeholders of a step's text, docstring and table
+pub(crate) fn fill_step(step: &mut Step, value: &impl Fn(&str) -> Option<String>) {
+    step.value = fill_placeholders(&step.value, value);
+    if let Some(docstring) = &mut step.docstring {
+        *docstring = fill_placeholders(docstring, value);
+    }
+    if let Some(table) = &mut step.table {
+        for cell in table.rows.iter_mut().flatten() {
+            *cell = fill_placeholders(cell, value);
+        }
+    }
+}
+
+fn expand_scenario(scenario: Scenario) -> Vec<Scenario> {
+    if scenario.examples.is_empty() {
+        return vec![scenario];
+    }
+
+    let mut expanded = vec![];
+    for examples in &scenario.examples {
+        let Some(table) = &examples.table else {
+            continue;
+        };
+        let Some((header, rows)) = table.rows.split_first() else {
+            continue;
+        };
+
+        for (i, row) in rows.iter().enumerate() {
+            let value = |name: &str| {
+                header
+                    .iter()
+                    .position(|column| column == name)
+                    .map(|column| row[column].clone())
+            };
+
+            let mut outline = scenario.clone();
+            // Cucumber tells scenarios apart by their position, so each row is positioned on its own line
+            outline.position = examples.position;
+            outline.position.line += i + 2;
+            outline.tags.extend(examples.tags.iter().cloned());
+            outline.name = fill_placeholders(&outline.name, &value);
+            for step in &mut outline.steps {
+                fill_step(step, &value);
+            }
+
+            let mut example = examples.clone();
+            example.table = Some(Table {
+                rows: vec![header.clone(), row.clone()],
+                ..table.clone()
+            });
+            outline.examples = vec![example];
+            expanded.push(outline);
+        }
+    }
+    expanded
+}